@@ -1,8 +1,63 @@
-use copy_to_output::copy_to_output;
 use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const MAX_SIZE: usize = 10;
+
+const DICTIONARIES: [(&str, &str); 2] = [
+    (
+        "OFFICIAL_GUESSES",
+        "dictionaries/dictionary-full-official.json",
+    ),
+    (
+        "OFFICIAL_ANSWERS",
+        "dictionaries/dictionary-answers-official.json",
+    ),
+];
 
 fn main() {
-    // Re-runs script if any files in res are changed
-    println!("cargo:rerun-if-changed=res/*");
-    copy_to_output("dictionaries", &env::var("PROFILE").unwrap()).expect("Could not copy");
+    // Bakes the official word lists into the binary as tables of words, so that
+    // nothing needs to be read or parsed at runtime
+    let mut code = String::new();
+    for (name, path) in DICTIONARIES {
+        println!("cargo:rerun-if-changed={path}");
+
+        let data = fs::read_to_string(path).expect("Unable to read dictionary");
+        let words = read_words(&data);
+
+        writeln!(code, "pub static {name}: [Word; {}] = [", words.len()).unwrap();
+        for word in words {
+            let mut letters = [0_u8; MAX_SIZE];
+            for (letter, byte) in letters.iter_mut().zip(word.bytes()) {
+                *letter = byte - b'A';
+            }
+            writeln!(code, "    Word::from_letters({letters:?}, {}),", word.len()).unwrap();
+        }
+        writeln!(code, "];").unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("official.rs"), code).expect("Unable to write dictionary");
+}
+
+/// Reads the words from a JSON array of strings.
+fn read_words(data: &str) -> Vec<String> {
+    let words: Vec<String> =
+        serde_json::from_str(data).expect("Dictionary is not a JSON array of strings");
+    words
+        .into_iter()
+        .map(|word| {
+            let word = word.to_ascii_uppercase();
+            assert!(
+                !word.is_empty() && word.len() <= MAX_SIZE,
+                "Invalid length: {word}"
+            );
+            assert!(
+                word.bytes().all(|b| b.is_ascii_uppercase()),
+                "Invalid word: {word}"
+            );
+            word
+        })
+        .collect()
 }
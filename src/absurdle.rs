@@ -0,0 +1,440 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::io::BufRead;
+
+use crate::boards::Scoreboard;
+use crate::dictionary::Dictionary;
+use crate::guess::{Algorithm, MinimaxAlgorithm};
+use crate::play::GuessError;
+use crate::reporting::{ConsoleReporter, NullReporter};
+use crate::scoring::{self, Score};
+use crate::solver::{Solver, SolverOptions};
+use crate::word::Word;
+
+/// Hosts a game of Absurdle. Rather than committing to an answer, the host scores
+/// each guess so as to keep as many answers alive as it can.
+pub struct AbsurdleHost<'a, T: Algorithm> {
+    solver: &'a Solver<T>,
+    potential_solns: Vec<usize>,
+}
+
+impl<'a, T: Algorithm> AbsurdleHost<'a, T> {
+    /// Hosts a game over the solver's dictionary, scoring guesses as the solver does.
+    pub fn new(solver: &'a Solver<T>) -> AbsurdleHost<'a, T> {
+        let potential_solns = (0..solver.dictionary().potential_solns.len()).collect();
+        AbsurdleHost {
+            solver,
+            potential_solns,
+        }
+    }
+
+    /// Scores `guess` with the score shared by the most remaining answers.
+    pub fn respond(&mut self, guess: &Word) -> Score {
+        let buckets = self.solver.partition(guess, &self.potential_solns);
+        let (score, potential_solns) = largest_bucket(buckets);
+        self.potential_solns = potential_solns;
+        score
+    }
+
+    pub fn num_left(&self) -> usize {
+        self.potential_solns.len()
+    }
+
+    /// Lets a person play against the host, reading guesses from `input`. The board is
+    /// drawn on stderr along with the prompts, leaving stdout for the result.
+    pub fn run_interactive(&mut self, input: &mut impl BufRead) -> Option<Scoreboard> {
+        eprintln!("Begin Absurdle. There is no answer until your guesses leave only one...\n");
+
+        let mut scoreboard = Scoreboard::default();
+        while !scoreboard.is_solved() {
+            let guess = self.read_guess(&scoreboard, input)?;
+            let score = self.respond(&guess);
+            scoreboard.add_row(None, guess, score, self.num_left());
+            eprintln!("{}", ConsoleReporter::report_tail(&scoreboard));
+        }
+
+        eprintln!("\nSolved in {} guesses.", scoreboard.len());
+        Some(scoreboard)
+    }
+
+    /// Reads a guess that is in the dictionary and, in hard mode, permitted by the `scoreboard`.
+    fn read_guess(&self, scoreboard: &Scoreboard, input: &mut impl BufRead) -> Option<Word> {
+        loop {
+            eprint!("Enter a guess: ");
+
+            let mut line = String::new();
+            if input.read_line(&mut line).ok()? == 0 {
+                eprintln!();
+                return None;
+            }
+
+            let dictionary = self.solver.dictionary();
+            match dictionary.parse_guess(line.trim()) {
+                Ok(guess) => {
+                    let index = dictionary.guess_index(&guess);
+                    let permitted = self.solver.candidate_guesses(scoreboard);
+                    if index.is_some_and(|index| permitted.binary_search(&index).is_ok()) {
+                        return Some(guess);
+                    }
+                    eprintln!("{}", GuessError::BreaksHardMode(guess));
+                }
+                Err(error) => eprintln!("{error}"),
+            }
+        }
+    }
+}
+
+/// The bucket an Absurdle host keeps. Ties go to the lowest score, which reveals the least.
+pub fn largest_bucket(buckets: BTreeMap<Score, Vec<usize>>) -> (Score, Vec<usize>) {
+    // `max_by_key` keeps the last maximum, so search from the highest score down
+    buckets
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, solns)| solns.len())
+        .unwrap()
+}
+
+/// Finds the shortest line of guesses that is guaranteed to beat an Absurdle host.
+///
+/// Iterative deepening tries ever longer lines, considering the `top_k` guesses with
+/// the smallest largest bucket at each position. The last guess before the answer
+/// must split every remaining answer apart, which is checked against every guess.
+/// In hard mode, each guess must use every hint the host has revealed so far.
+pub struct AbsurdleSolver {
+    solver: Solver<MinimaxAlgorithm>,
+    top_k: usize,
+    max_depth: usize,
+    hard_mode: bool,
+    dead_ends: RefCell<HashMap<Position, usize>>,
+}
+
+/// The remaining answers, and the guesses hard mode still allows.
+type Position = (Vec<usize>, Option<Vec<usize>>);
+
+impl AbsurdleSolver {
+    /// A `top_k` of zero searches every guess, which proves the line found is optimal.
+    pub fn new(
+        dictionary: Dictionary,
+        options: SolverOptions,
+        top_k: usize,
+        max_depth: usize,
+    ) -> AbsurdleSolver {
+        let top_k = match top_k {
+            0 => dictionary.all_words.len(),
+            _ => top_k,
+        };
+        let hard_mode = options.hard_mode;
+        let solver = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            options,
+        );
+
+        AbsurdleSolver {
+            solver,
+            top_k,
+            max_depth,
+            hard_mode,
+            dead_ends: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Whether the search tries every guess, so that no shorter line can exist.
+    pub fn is_exhaustive(&self) -> bool {
+        self.top_k >= self.solver.dictionary().all_words.len()
+    }
+
+    /// The shortest line found, as the scoreboard of playing it against the host.
+    pub fn solve(&self, opening_guess: Option<Word>) -> Option<Scoreboard> {
+        let dictionary = self.solver.dictionary();
+        let all_guesses: Vec<usize> = (0..dictionary.all_words.len()).collect();
+        let potential_solns: Vec<usize> = (0..dictionary.potential_solns.len()).collect();
+
+        let line = (1..=self.max_depth).find_map(|depth| match &opening_guess {
+            Some(guess) => self.follow(&all_guesses, guess, &potential_solns, depth),
+            None => self.search(&all_guesses, &potential_solns, depth),
+        })?;
+
+        // Replay the line to record the host's scores
+        let mut host = AbsurdleHost::new(&self.solver);
+        let mut scoreboard = Scoreboard::default();
+        for guess in line {
+            let score = host.respond(&guess);
+            scoreboard.add_row(None, guess, score, host.num_left());
+        }
+
+        Some(scoreboard)
+    }
+
+    /// Finds a line of at most `depth` of the `guesses` that solves `potential_solns`.
+    fn search(
+        &self,
+        guesses: &[usize],
+        potential_solns: &[usize],
+        depth: usize,
+    ) -> Option<Vec<Word>> {
+        let dictionary = self.solver.dictionary();
+        if potential_solns.len() == 1 && depth >= 1 {
+            return Some(vec![dictionary.potential_solns[potential_solns[0]].clone()]);
+        }
+
+        if depth < 2 {
+            return None;
+        }
+
+        let position = (
+            potential_solns.to_vec(),
+            self.hard_mode.then(|| guesses.to_vec()),
+        );
+        let failed = self.dead_ends.borrow().get(&position).copied();
+        if failed.is_some_and(|failed| failed >= depth) {
+            return None;
+        }
+
+        // Two guesses are only enough if the best guess splits every answer apart
+        let top_k = if depth == 2 { 1 } else { self.top_k };
+
+        for guess in self.solver.ranked_guesses(guesses, potential_solns, top_k) {
+            let guess = &dictionary.all_words[guess];
+            if let Some(line) = self.follow(guesses, guess, potential_solns, depth) {
+                return Some(line);
+            }
+        }
+
+        self.dead_ends.borrow_mut().insert(position, depth);
+        None
+    }
+
+    fn follow(
+        &self,
+        guesses: &[usize],
+        guess: &Word,
+        potential_solns: &[usize],
+        depth: usize,
+    ) -> Option<Vec<Word>> {
+        let buckets = self.solver.partition(guess, potential_solns);
+        let (score, remaining) = largest_bucket(buckets);
+        if score == scoring::max_score(guess.len()) {
+            return Some(vec![guess.clone()]);
+        }
+
+        // A guess that rules nothing out can never be part of the shortest line
+        if remaining.len() == potential_solns.len() || depth < 2 {
+            return None;
+        }
+
+        let hard_guesses: Vec<usize>;
+        let next_guesses = if self.hard_mode {
+            let all_words = &self.solver.dictionary().all_words;
+            hard_guesses = guesses
+                .iter()
+                .copied()
+                .filter(|&next| scoring::satisfies_hard_mode(&all_words[next], guess, score))
+                .collect();
+            &hard_guesses
+        } else {
+            guesses
+        };
+
+        let mut line = self.search(next_guesses, &remaining, depth - 1)?;
+        line.insert(0, guess.clone());
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::fixtures::{dictionary, skill_family};
+    use rstest::{fixture, rstest};
+    use std::io::Cursor;
+
+    #[rstest]
+    fn respond__for_guess__keeps_largest_bucket(solver: Solver<MinimaxAlgorithm>) {
+        // Arrange
+        let mut sut = AbsurdleHost::new(&solver);
+
+        // Act
+        let actual = sut.respond(&"SALET".parse().unwrap());
+
+        // Assert
+        // ROWER and POWER both only share the E, whereas TOWER also has the T
+        assert_eq!(scoring::str_to_score("00020"), actual);
+        assert_eq!(2, sut.num_left());
+    }
+
+    #[rstest]
+    fn respond__for_tie__reveals_least(solver: Solver<MinimaxAlgorithm>) {
+        // Arrange
+        let mut sut = AbsurdleHost::new(&solver);
+        sut.respond(&"SALET".parse().unwrap());
+
+        // Act
+        let actual = sut.respond(&"ROWER".parse().unwrap());
+
+        // Assert
+        assert_eq!(scoring::str_to_score("02222"), actual);
+        assert_eq!(1, sut.num_left());
+    }
+
+    #[rstest]
+    fn run_interactive__with_guesses__plays_until_solved(solver: Solver<MinimaxAlgorithm>) {
+        // Arrange
+        let mut sut = AbsurdleHost::new(&solver);
+        let mut input = Cursor::new("salet\nlower\nrower\npower\n");
+
+        // Act
+        let actual = sut.run_interactive(&mut input);
+
+        // Assert
+        let guesses: Vec<String> = actual
+            .unwrap()
+            .rows
+            .iter()
+            .map(|r| r.guess.value())
+            .collect();
+        assert_eq!(vec!["SALET", "ROWER", "POWER"], guesses);
+    }
+
+    #[rstest]
+    fn run_interactive__in_hard_mode__refuses_guesses_that_ignore_hints(dictionary: Dictionary) {
+        // Arrange
+        let options = SolverOptions {
+            hard_mode: true,
+            ..Default::default()
+        };
+        let solver = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            options,
+        );
+        let mut sut = AbsurdleHost::new(&solver);
+
+        // SALET reveals an E in fourth place, which SOARE does not use
+        let mut input = Cursor::new("salet\nsoare\nrower\npower\n");
+
+        // Act
+        let actual = sut.run_interactive(&mut input);
+
+        // Assert
+        let guesses: Vec<String> = actual
+            .unwrap()
+            .rows
+            .iter()
+            .map(|r| r.guess.value())
+            .collect();
+        assert_eq!(vec!["SALET", "ROWER", "POWER"], guesses);
+    }
+
+    #[rstest]
+    fn run_interactive__at_end_of_input__returns_none(solver: Solver<MinimaxAlgorithm>) {
+        // Arrange
+        let mut sut = AbsurdleHost::new(&solver);
+        let mut input = Cursor::new("salet\n");
+
+        // Act
+        let actual = sut.run_interactive(&mut input);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[rstest]
+    #[case(None, vec!["KAPOW", "STILL"])]
+    #[case(Some("SKILL"), vec!["SKILL", "KAPOW", "STILL"])]
+    fn solve__for_skill_family__finds_shortest_line(
+        #[case] opening_guess: Option<&str>,
+        #[case] expected: Vec<&str>,
+    ) {
+        // Arrange
+        let sut = AbsurdleSolver::new(skill_family(), SolverOptions::default(), 0, 6);
+        let opening_guess = opening_guess.map(|guess| guess.parse().unwrap());
+
+        // Act
+        let actual = sut.solve(opening_guess);
+
+        // Assert
+        let scoreboard = actual.unwrap();
+        let guesses: Vec<String> = scoreboard.rows.iter().map(|r| r.guess.value()).collect();
+        assert_eq!(expected, guesses);
+        assert!(scoreboard.is_solved());
+        assert!(sut.is_exhaustive());
+    }
+
+    #[rstest]
+    #[case(false, 3)]
+    #[case(true, 4)]
+    fn solve__in_hard_mode__only_plays_permitted_guesses(
+        #[case] hard_mode: bool,
+        #[case] expected_len: usize,
+    ) {
+        // Arrange
+        let options = SolverOptions {
+            hard_mode,
+            ..Default::default()
+        };
+        let sut = AbsurdleSolver::new(skill_family(), options, 0, 6);
+
+        // Act
+        let actual = sut.solve(Some("SKILL".parse().unwrap()));
+
+        // Assert
+        // After SKILL, hard mode rules out KAPOW, so the host can only be beaten one word at a time
+        let scoreboard = actual.unwrap();
+        assert_eq!(expected_len, scoreboard.len());
+        assert!(scoreboard.is_solved());
+        if hard_mode {
+            for (n, row) in scoreboard.rows.iter().enumerate() {
+                let previous = Scoreboard {
+                    rows: scoreboard.rows[..n].to_vec(),
+                };
+                assert!(
+                    previous.permits(&row.guess),
+                    "{} broke hard mode",
+                    row.guess
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    fn solve__beyond_depth_limit__returns_none() {
+        // Arrange
+        let sut = AbsurdleSolver::new(skill_family(), SolverOptions::default(), 0, 1);
+
+        // Act
+        let actual = sut.solve(None);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[rstest]
+    fn solve__for_fixture__matches_playing_the_host(dictionary: Dictionary) {
+        // Arrange
+        let sut = AbsurdleSolver::new(dictionary, SolverOptions::default(), 2, 6);
+
+        // Act
+        let actual = sut.solve(None);
+
+        // Assert
+        // No guess separates ROWER and POWER, so at best SALET leaves them for two more
+        let scoreboard = actual.unwrap();
+        assert_eq!(3, scoreboard.len());
+        assert!(!sut.is_exhaustive());
+    }
+
+    #[fixture]
+    fn solver(dictionary: Dictionary) -> Solver<MinimaxAlgorithm> {
+        Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            SolverOptions::default(),
+        )
+    }
+}
@@ -0,0 +1,302 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result};
+
+use crate::boards::Scoreboard;
+use crate::guess::{Algorithm, GuessMetrics};
+use crate::reporting::ConsoleReporter;
+use crate::scoring::{self, score_to_str, Score};
+use crate::solver::Solver;
+use crate::word::Word;
+
+/// The best possible skill or luck rating, as used by the NYT's WordleBot.
+const MAX_RATING: f64 = 99.0;
+
+/// How one guess of a game compared to the solver's choice.
+#[derive(Debug, Clone, Serialize)]
+pub struct Turn {
+    /// The number of answers still possible before the guess.
+    pub num_candidates: usize,
+
+    pub guess: GuessMetrics,
+
+    /// The guess the algorithm ranks first, which skill is measured against. This is
+    /// the greedy choice, even when the solver searches or looks ahead.
+    pub best: GuessMetrics,
+
+    /// Out of 99, the share of every allowed guess that the algorithm ranks no better.
+    pub skill: f64,
+
+    /// Out of 99, the chance that the guess would have left more answers than it did,
+    /// counting an equal number as half.
+    pub luck: f64,
+}
+
+/// Grades each guess a person played, like the NYT's WordleBot.
+pub struct Analysis {
+    pub scoreboard: Scoreboard,
+    pub turns: Vec<Turn>,
+}
+
+impl Analysis {
+    /// Replays `guesses` against `soln`, which must all be words in the solver's dictionary.
+    pub fn new<T: Algorithm>(solver: &Solver<T>, soln: &Word, guesses: &[Word]) -> Analysis {
+        let dictionary = solver.dictionary();
+        let mut potential_solns: Vec<usize> = (0..dictionary.potential_solns.len()).collect();
+        let mut scoreboard = Scoreboard::default();
+        let mut turns = Vec::new();
+
+        for guess in guesses {
+            let index = dictionary
+                .guess_index(guess)
+                .expect("Guesses are validated against the dictionary");
+            let candidates = solver.candidate_guesses(&scoreboard);
+
+            // Skill is ranked greedily, so the best guess must be too for the two to agree
+            let best = solver.ranked_guesses(&candidates, &potential_solns, 1)[0];
+            let num_better = solver.num_better(index, &candidates, &potential_solns);
+
+            let score = scoring::score(guess, soln);
+            let mut partitions = solver.partition(guess, &potential_solns);
+
+            turns.push(Turn {
+                num_candidates: potential_solns.len(),
+                guess: solver.metrics(index, &potential_solns),
+                best: solver.metrics(best, &potential_solns),
+                skill: skill(num_better, candidates.len()),
+                luck: luck(&partitions, score, scoring::max_score(guess.len())),
+            });
+
+            potential_solns = partitions.remove(&score).unwrap_or_default();
+            scoreboard.add_row(
+                Some(soln.clone()),
+                guess.clone(),
+                score,
+                potential_solns.len(),
+            );
+        }
+
+        Analysis { scoreboard, turns }
+    }
+
+    pub fn mean_skill(&self) -> f64 {
+        self.turns.iter().map(|turn| turn.skill).sum::<f64>() / self.turns.len() as f64
+    }
+
+    pub fn mean_luck(&self) -> f64 {
+        self.turns.iter().map(|turn| turn.luck).sum::<f64>() / self.turns.len() as f64
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "rows": self.scoreboard.rows,
+            "turns": self.turns,
+            "skill": self.mean_skill(),
+            "luck": self.mean_luck(),
+        })
+    }
+}
+
+fn skill(num_better: usize, num_guesses: usize) -> f64 {
+    if num_guesses <= 1 {
+        return MAX_RATING;
+    }
+
+    MAX_RATING * (1.0 - num_better as f64 / (num_guesses - 1) as f64)
+}
+
+fn luck(partitions: &BTreeMap<Score, Vec<usize>>, observed_score: Score, max_score: Score) -> f64 {
+    // A win leaves nothing left to find
+    let num_left = |score: Score, partition: &[usize]| {
+        if score == max_score {
+            0
+        } else {
+            partition.len()
+        }
+    };
+
+    let num_solns: usize = partitions.values().map(Vec::len).sum();
+    let observed = num_left(observed_score, &partitions[&observed_score]);
+
+    let fortune: f64 = partitions
+        .iter()
+        .map(|(&score, partition)| {
+            let num_left = num_left(score, partition);
+            let weight = match num_left.cmp(&observed) {
+                Ordering::Greater => 1.0,
+                Ordering::Equal => 0.5,
+                Ordering::Less => 0.0,
+            };
+            weight * partition.len() as f64
+        })
+        .sum();
+
+    MAX_RATING * fortune / num_solns as f64
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let size = self
+            .scoreboard
+            .rows
+            .first()
+            .map_or(5, |row| row.guess.len());
+        let width = ConsoleReporter::column_width(size);
+        let padding = " ".repeat(width - size);
+        let line = "-".repeat(width + 2);
+
+        writeln!(
+            f,
+            "| # | {:<width$} | Before |  Bits | Largest | Left | {:<width$} |  Bits | Skill | Luck |",
+            "Guess", "Best"
+        )?;
+        writeln!(
+            f,
+            "|---|{line}|--------|-------|---------|------|{line}|-------|-------|------|"
+        )?;
+
+        for (row, turn) in self.scoreboard.rows.iter().zip(&self.turns) {
+            let ternary = score_to_str(row.score, size);
+            let guess = ConsoleReporter::prettify(&row.guess.value(), &ternary);
+            let left = if row.is_solved() { 0 } else { row.num_left };
+
+            writeln!(
+                f,
+                "| {} | {guess}{padding} | {: >6} | {: >5.2} | {: >7} | {left: >4} | {:<width$} | {: >5.2} | {: >5.0} | {: >4.0} |",
+                row.n,
+                turn.num_candidates,
+                turn.guess.entropy,
+                turn.guess.largest_bucket,
+                turn.best.word.value(),
+                turn.best.entropy,
+                turn.skill,
+                turn.luck,
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Skill: {:.0}/99", self.mean_skill())?;
+        write!(f, "Luck: {:.0}/99", self.mean_luck())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::fixtures::skill_family;
+    use crate::guess::EntropyAlgorithm;
+    use crate::lookahead::Lookahead;
+    use crate::reporting::NullReporter;
+    use crate::search::SearchOptions;
+    use crate::solver::SolverOptions;
+    use float_cmp::assert_approx_eq;
+    use rstest::{fixture, rstest};
+
+    #[rstest]
+    fn new__for_skill_family__grades_each_guess(analysis: Analysis) {
+        // Act
+        let actual: Vec<(String, f64, f64)> = analysis
+            .turns
+            .iter()
+            .map(|turn| (turn.best.word.value(), turn.skill, turn.luck))
+            .collect();
+
+        // Assert
+        // KAPOW splits any three answers apart, so is the only better guess in either of
+        // the first two turns. STILL is left with the other answer each time
+        let expected = [
+            ("KAPOW", 74.25, 37.125),
+            ("KAPOW", 74.25, 33.0),
+            ("STILL", 99.0, 74.25),
+        ];
+        for ((word, skill, luck), (expected_word, expected_skill, expected_luck)) in
+            actual.into_iter().zip(expected)
+        {
+            assert_eq!(expected_word, word);
+            assert_approx_eq!(f64, expected_skill, skill, epsilon = 1e-9);
+            assert_approx_eq!(f64, expected_luck, luck, epsilon = 1e-9);
+        }
+
+        let num_candidates: Vec<usize> = analysis.turns.iter().map(|t| t.num_candidates).collect();
+        assert_eq!(vec![4, 3, 2], num_candidates);
+        assert!(analysis.scoreboard.is_solved());
+        assert_approx_eq!(f64, 82.5, analysis.mean_skill(), epsilon = 1e-9);
+        assert_approx_eq!(f64, 48.125, analysis.mean_luck(), epsilon = 1e-9);
+    }
+
+    #[rstest]
+    fn new__for_best_guess__rates_full_skill(
+        #[values(
+            SolverOptions::default(),
+            SolverOptions { search: Some(SearchOptions::default()), ..Default::default() },
+            SolverOptions { lookahead: Some(Lookahead::default()), ..Default::default() }
+        )]
+        options: SolverOptions,
+    ) {
+        // Arrange
+        let solver = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            skill_family(),
+            options,
+        );
+        let guesses: Vec<Word> = vec!["KAPOW".parse().unwrap(), "STILL".parse().unwrap()];
+
+        // Act
+        let actual = Analysis::new(&solver, &"STILL".parse().unwrap(), &guesses);
+
+        // Assert
+        for turn in actual.turns {
+            assert_eq!(turn.best.word, turn.guess.word);
+            assert_approx_eq!(f64, MAX_RATING, turn.skill, epsilon = 1e-9);
+        }
+    }
+
+    #[rstest]
+    fn display__for_skill_family__tabulates_turns(analysis: Analysis) {
+        // Arrange
+        colored::control::set_override(false);
+
+        // Act
+        let actual = format!("{analysis}");
+
+        // Assert
+        let expected_row =
+            "| 1 | SKILL |      4 |  0.81 |       3 |    3 | KAPOW |  2.00 |    74 |   37 |";
+        assert!(actual.lines().any(|line| line == expected_row), "{actual}");
+        assert!(actual.ends_with("Skill: 82/99\nLuck: 48/99"));
+    }
+
+    #[rstest]
+    fn to_json__for_skill_family__pairs_rows_with_turns(analysis: Analysis) {
+        // Act
+        let actual = analysis.to_json();
+
+        // Assert
+        assert_eq!("SPILL", actual["rows"][1]["guess"]);
+        assert_eq!("KAPOW", actual["turns"][1]["best"]["word"]);
+        assert_eq!(3, actual["turns"][1]["num_candidates"]);
+        assert_eq!(82.5, actual["skill"]);
+    }
+
+    #[fixture]
+    fn analysis() -> Analysis {
+        let solver = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            skill_family(),
+            SolverOptions::default(),
+        );
+
+        let guesses: Vec<Word> = ["SKILL", "SPILL", "STILL"]
+            .iter()
+            .map(|w| w.parse().unwrap())
+            .collect();
+
+        Analysis::new(&solver, &"STILL".parse().unwrap(), &guesses)
+    }
+}
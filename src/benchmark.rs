@@ -0,0 +1,229 @@
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result};
+use std::time::{Duration, Instant};
+
+use crate::boards::MAX_GUESSES;
+use crate::solver::Solve;
+use crate::word::Word;
+
+pub struct Benchmark {
+    pub opening_guess: Word,
+    pub results: Vec<(Word, Option<usize>)>,
+    pub elapsed: Duration,
+}
+
+impl Benchmark {
+    pub fn run(solver: &dyn Solve, solns: &[Word], opening_guess: Option<Word>) -> Benchmark {
+        let now = Instant::now();
+
+        // Every game shares the same opening so only search for it once
+        let opening_guess = opening_guess.unwrap_or_else(|| solver.opening_guess());
+
+        let results = solns
+            .iter()
+            .map(|soln| {
+                let scoreboard = solver.solve(soln, Some(opening_guess.clone()));
+                (soln.clone(), scoreboard.map(|s| s.len()))
+            })
+            .collect();
+
+        Benchmark {
+            opening_guess,
+            results,
+            elapsed: now.elapsed(),
+        }
+    }
+
+    pub fn distribution(&self) -> BTreeMap<usize, usize> {
+        let mut distribution = BTreeMap::new();
+        for num_guesses in self.results.iter().filter_map(|(_, n)| *n) {
+            *distribution.entry(num_guesses).or_insert(0) += 1;
+        }
+
+        distribution
+    }
+
+    pub fn mean(&self) -> f64 {
+        let solved: Vec<usize> = self.results.iter().filter_map(|(_, n)| *n).collect();
+        if solved.is_empty() {
+            return 0.0;
+        }
+
+        solved.iter().sum::<usize>() as f64 / solved.len() as f64
+    }
+
+    pub fn max(&self) -> usize {
+        self.results
+            .iter()
+            .filter_map(|(_, n)| *n)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The answers that were not solved within the guesses Wordle allows.
+    pub fn failures(&self) -> Vec<&Word> {
+        self.results
+            .iter()
+            .filter(|(_, n)| !n.is_some_and(|n| n <= MAX_GUESSES))
+            .map(|(soln, _)| soln)
+            .collect()
+    }
+
+    pub fn to_json(&self) -> Value {
+        let distribution: Map<String, Value> = self
+            .distribution()
+            .into_iter()
+            .map(|(num_guesses, count)| (num_guesses.to_string(), json!(count)))
+            .collect();
+        let results: Vec<Value> = self
+            .results
+            .iter()
+            .map(|(soln, num_guesses)| json!({ "soln": soln, "num_guesses": num_guesses }))
+            .collect();
+
+        json!({
+            "opening": self.opening_guess,
+            "stats": {
+                "games": self.results.len(),
+                "mean": self.mean(),
+                "max": self.max(),
+                "distribution": distribution,
+                "failures": self.failures(),
+            },
+            "elapsed_ms": self.elapsed.as_secs_f64() * 1000.0,
+            "results": results,
+        })
+    }
+}
+
+impl Display for Benchmark {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let num_games = self.results.len();
+        writeln!(
+            f,
+            "Opening with {} over {num_games} games",
+            self.opening_guess
+        )?;
+        writeln!(f)?;
+        writeln!(f, "| Guesses | Games |")?;
+        writeln!(f, "|---------|-------|")?;
+        for (num_guesses, count) in self.distribution() {
+            writeln!(f, "| {num_guesses: >7} | {count: >5} |")?;
+        }
+
+        let failures = self.failures();
+        let failed_words: Vec<String> = failures.iter().map(|w| w.value()).collect();
+
+        writeln!(f)?;
+        writeln!(f, "Mean: {:.4}", self.mean())?;
+        writeln!(f, "Max: {}", self.max())?;
+        writeln!(f, "Failures: {} {:?}", failures.len(), failed_words)?;
+        write!(f, "Elapsed: {:.2?}", self.elapsed)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use crate::dictionary::Dictionary;
+    use crate::guess::MinimaxAlgorithm;
+    use crate::reporting::NullReporter;
+    use crate::solver::{Solver, SolverOptions};
+
+    use super::*;
+    use crate::fixtures::dictionary;
+    use float_cmp::assert_approx_eq;
+    use rstest::{fixture, rstest};
+
+    #[rstest]
+    fn run__over_all_solutions__solves_each(dictionary: Dictionary) {
+        // Arrange
+        let solns = dictionary.potential_solns.clone();
+        let options = SolverOptions::default();
+        let solver = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            options,
+        );
+
+        // Act
+        let actual = Benchmark::run(&solver, &solns, None);
+
+        // Assert
+        assert_eq!(3, actual.results.len());
+        assert!(actual.failures().is_empty());
+    }
+
+    #[rstest]
+    fn statistics__for_results__summarise(benchmark: Benchmark) {
+        // Act
+        let distribution = benchmark.distribution();
+        let mean = benchmark.mean();
+        let max = benchmark.max();
+        let failures = benchmark.failures();
+
+        // Assert
+        assert_eq!(Some(&2), distribution.get(&3));
+        assert_eq!(Some(&1), distribution.get(&4));
+        assert_approx_eq!(f64, 10.0 / 3.0, mean, epsilon = 1e-9);
+        assert_eq!(4, max);
+        let expected: Word = "POWER".parse().unwrap();
+        assert_eq!(vec![&expected], failures);
+    }
+
+    #[rstest]
+    fn failures__for_game_beyond_six_guesses__includes_it(mut benchmark: Benchmark) {
+        // Arrange
+        benchmark.results.push(("BOWER".parse().unwrap(), Some(7)));
+
+        // Act
+        let actual = benchmark.failures();
+
+        // Assert
+        let expected: Vec<Word> = vec!["POWER".parse().unwrap(), "BOWER".parse().unwrap()];
+        assert_eq!(expected.iter().collect::<Vec<_>>(), actual);
+    }
+
+    #[rstest]
+    fn display__for_results__formats(benchmark: Benchmark) {
+        // Act
+        let actual = format!("{benchmark}");
+
+        // Assert
+        assert!(actual.contains("Mean: 3.3333"));
+        assert!(actual.contains("Failures: 1 [\"POWER\"]"));
+    }
+
+    #[rstest]
+    fn to_json__for_results__summarises_each_game(benchmark: Benchmark) {
+        // Act
+        let actual = benchmark.to_json();
+
+        // Assert
+        assert_eq!("SALET", actual["opening"]);
+        assert_eq!(4, actual["stats"]["games"]);
+        assert_eq!(2, actual["stats"]["distribution"]["3"]);
+        assert_eq!("POWER", actual["stats"]["failures"][0]);
+        assert_eq!(42.0, actual["elapsed_ms"]);
+        assert_eq!(Value::Null, actual["results"][2]["num_guesses"]);
+    }
+
+    #[fixture]
+    fn benchmark() -> Benchmark {
+        let results = vec![
+            ("TOWER".parse().unwrap(), Some(3)),
+            ("ROWER".parse().unwrap(), Some(4)),
+            ("POWER".parse().unwrap(), None),
+            ("LOWER".parse().unwrap(), Some(3)),
+        ];
+
+        Benchmark {
+            opening_guess: "SALET".parse().unwrap(),
+            results,
+            elapsed: Duration::from_millis(42),
+        }
+    }
+}
@@ -1,6 +1,14 @@
-use crate::{scoring::MAX_SCORE, word::Word};
+use serde::{Deserialize, Serialize};
 
-#[derive(Default)]
+use crate::{
+    scoring::{self, Score},
+    word::Word,
+};
+
+/// The number of guesses Wordle allows to find the answer.
+pub const MAX_GUESSES: usize = 6;
+
+#[derive(Default, Clone, Serialize, Deserialize)]
 pub struct Scoreboard {
     pub rows: Vec<ScoreboardRow>,
 }
@@ -12,12 +20,19 @@ impl Scoreboard {
 
     pub fn is_solved(&self) -> bool {
         match self.rows.last() {
-            Some(row) => row.score == MAX_SCORE as u8,
+            Some(row) => row.is_solved(),
             None => false,
         }
     }
 
-    pub fn add_row(&mut self, soln: Word, guess: Word, score: u8, num_left: usize) {
+    /// Checks that a guess uses every hint revealed so far, as hard mode requires.
+    pub fn permits(&self, guess: &Word) -> bool {
+        self.rows
+            .iter()
+            .all(|row| scoring::satisfies_hard_mode(guess, &row.guess, row.score))
+    }
+
+    pub fn add_row(&mut self, soln: Option<Word>, guess: Word, score: Score, num_left: usize) {
         let row = ScoreboardRow {
             n: self.len() as u32 + 1_u32,
             soln,
@@ -30,11 +45,102 @@ impl Scoreboard {
     }
 }
 
-#[derive(Debug)]
+/// Written to JSON with the score in ternary, as in `{"n": 1, "soln": "POWER",
+/// "guess": "SALET", "score": "00020", "num_left": 2}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "RowJson", try_from = "RowJson")]
 pub struct ScoreboardRow {
     pub n: u32,
-    pub soln: Word,
+    pub soln: Option<Word>,
     pub guess: Word,
-    pub score: u8,
+    pub score: Score,
     pub num_left: usize,
 }
+
+impl ScoreboardRow {
+    pub fn is_solved(&self) -> bool {
+        self.score == scoring::max_score(self.guess.len())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RowJson {
+    n: u32,
+    soln: Option<Word>,
+    guess: Word,
+    score: String,
+    num_left: usize,
+}
+
+impl From<ScoreboardRow> for RowJson {
+    fn from(row: ScoreboardRow) -> Self {
+        RowJson {
+            n: row.n,
+            score: scoring::score_to_str(row.score, row.guess.len()),
+            soln: row.soln,
+            guess: row.guess,
+            num_left: row.num_left,
+        }
+    }
+}
+
+impl TryFrom<RowJson> for ScoreboardRow {
+    type Error = String;
+
+    fn try_from(row: RowJson) -> Result<Self, Self::Error> {
+        let Some(score) = scoring::parse_score(&row.score, row.guess.len()) else {
+            return Err(format!("'{}' is not a score for {}", row.score, row.guess));
+        };
+
+        Ok(ScoreboardRow {
+            n: row.n,
+            soln: row.soln,
+            guess: row.guess,
+            score,
+            num_left: row.num_left,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[rstest]
+    fn serialize__then_deserialize__roundtrips() {
+        // Arrange
+        let mut scoreboard = Scoreboard::default();
+        let guess: Word = "SALET".parse().unwrap();
+        scoreboard.add_row(None, guess, scoring::str_to_score("00020"), 2);
+
+        // Act
+        let json = serde_json::to_value(&scoreboard).unwrap();
+        let actual: Scoreboard = serde_json::from_value(json.clone()).unwrap();
+
+        // Assert
+        let expected = json!({
+            "rows": [{"n": 1, "soln": null, "guess": "SALET", "score": "00020", "num_left": 2}]
+        });
+        assert_eq!(expected, json);
+        assert_eq!(scoreboard.rows[0].score, actual.rows[0].score);
+        assert_eq!(scoreboard.rows[0].guess, actual.rows[0].guess);
+    }
+
+    #[rstest]
+    #[case("0002")]
+    #[case("00030")]
+    fn deserialize__for_bad_score__errors(#[case] score: &str) {
+        // Arrange
+        let json = json!({"rows": [{"n": 1, "soln": null, "guess": "SALET", "score": score, "num_left": 2}]});
+
+        // Act
+        let actual = serde_json::from_value::<Scoreboard>(json);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+}
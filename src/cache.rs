@@ -0,0 +1,169 @@
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::files;
+use crate::scoring::{self, Score};
+use crate::word::Word;
+
+/// The guesses a solver makes at the start of every game, which never change for
+/// a given dictionary and algorithm.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Openings {
+    /// The solver's own choice of opening guess.
+    pub opening: Option<Word>,
+
+    /// The second guess after each opening guess, for every score it could receive.
+    pub replies: BTreeMap<Word, BTreeMap<Score, Word>>,
+}
+
+/// Opening and second guesses saved to a JSON file, keyed by the dictionary and
+/// algorithm that chose them, so that later runs need not search for them again.
+pub struct OpeningCache {
+    path: PathBuf,
+    entries: BTreeMap<String, Openings>,
+}
+
+impl OpeningCache {
+    /// Reads the cache at `path`, starting afresh if it is missing or unreadable.
+    pub fn load(path: &Path) -> OpeningCache {
+        let entries = fs::read_to_string(path)
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .and_then(|json| OpeningCache::from_json(&json))
+            .unwrap_or_default();
+
+        OpeningCache {
+            path: path.to_path_buf(),
+            entries,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Openings> {
+        self.entries.get(key)
+    }
+
+    pub fn entry(&mut self, key: &str) -> &mut Openings {
+        self.entries.entry(key.to_string()).or_default()
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let data = serde_json::to_string_pretty(&self.to_json())?;
+        files::atomic_write(&self.path, data)
+    }
+
+    fn to_json(&self) -> Value {
+        let entries: Map<String, Value> = self
+            .entries
+            .iter()
+            .map(|(key, openings)| {
+                let replies: Map<String, Value> = openings
+                    .replies
+                    .iter()
+                    .map(|(opening, replies)| {
+                        let replies: Map<String, Value> = replies
+                            .iter()
+                            .map(|(&score, guess)| {
+                                let ternary = scoring::score_to_str(score, opening.len());
+                                (ternary, json!(guess.value()))
+                            })
+                            .collect();
+                        (opening.value(), Value::Object(replies))
+                    })
+                    .collect();
+
+                let opening = openings.opening.as_ref().map(Word::value);
+                (
+                    key.clone(),
+                    json!({ "opening": opening, "replies": replies }),
+                )
+            })
+            .collect();
+
+        Value::Object(entries)
+    }
+
+    fn from_json(json: &Value) -> Option<BTreeMap<String, Openings>> {
+        let parse_word = |value: &Value| value.as_str()?.parse::<Word>().ok();
+
+        json.as_object()?
+            .iter()
+            .map(|(key, entry)| {
+                let opening = match entry.get("opening")? {
+                    Value::Null => None,
+                    value => Some(parse_word(value)?),
+                };
+
+                let mut replies = BTreeMap::new();
+                for (guess, scores) in entry.get("replies")?.as_object()? {
+                    let guess: Word = guess.parse().ok()?;
+                    let scores = scores
+                        .as_object()?
+                        .iter()
+                        .map(|(ternary, reply)| {
+                            let score = scoring::parse_score(ternary, guess.len())?;
+                            Some((score, parse_word(reply)?))
+                        })
+                        .collect::<Option<_>>()?;
+                    replies.insert(guess, scores);
+                }
+
+                Some((key.clone(), Openings { opening, replies }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use rstest::rstest;
+    use std::env;
+
+    #[rstest]
+    fn save__then_load__roundtrips() {
+        // Arrange
+        let path = env::temp_dir().join("doddle-cache-roundtrip.json");
+        let mut sut = OpeningCache::load(&path);
+        let openings = sut.entry("key");
+        openings.opening = Some("SALET".parse().unwrap());
+        openings.replies.insert(
+            "SALET".parse().unwrap(),
+            BTreeMap::from([(scoring::str_to_score("00020"), "ROWER".parse().unwrap())]),
+        );
+
+        // Act
+        sut.save().unwrap();
+        let actual = OpeningCache::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        assert_eq!(sut.get("key"), actual.get("key"));
+        assert!(actual.get("other").is_none());
+    }
+
+    #[rstest]
+    #[case("not json")]
+    #[case("{\"key\": {\"opening\": \"S4LET\", \"replies\": {}}}")]
+    #[case("{\"key\": {\"opening\": null, \"replies\": {\"SALET\": {\"3\": \"ROWER\"}}}}")]
+    fn load__for_corrupt_file__starts_afresh(#[case] contents: &str) {
+        // Arrange
+        let path = env::temp_dir().join(format!("doddle-cache-corrupt-{}.json", contents.len()));
+        fs::write(&path, contents).unwrap();
+
+        // Act
+        let actual = OpeningCache::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        assert!(actual.get("key").is_none());
+    }
+}
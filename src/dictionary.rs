@@ -1,43 +1,520 @@
 use itertools::Itertools;
 use serde_json::Value;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 
-use crate::word::Word;
+use crate::word::{Word, WordError, DEFAULT_SIZE};
 
-const ALL_WORDS: &str = "./dictionaries/dictionary-full-official.json";
-const SOLUTIONS: &str = "./dictionaries/dictionary-answers-official.json";
+// The official five letter lists, generated from `dictionaries/` by the build script
+include!(concat!(env!("OUT_DIR"), "/official.rs"));
 
+#[derive(Debug)]
+pub enum DictionaryError {
+    Unreadable { path: PathBuf, source: io::Error },
+    Malformed { path: PathBuf, reason: String },
+    Empty(PathBuf),
+    InvalidWord { path: PathBuf, source: WordError },
+    InvalidFrequency { path: PathBuf, reason: String },
+    NoOfficialList(usize),
+}
+
+impl Display for DictionaryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            DictionaryError::Unreadable { path, source } => {
+                write!(f, "Unable to read word list {}: {source}", path.display())
+            }
+            DictionaryError::Malformed { path, reason } => {
+                write!(
+                    f,
+                    "Word list {} is not valid JSON: {reason}",
+                    path.display()
+                )
+            }
+            DictionaryError::Empty(path) => {
+                write!(f, "Word list {} does not contain any words", path.display())
+            }
+            DictionaryError::InvalidWord { path, source } => {
+                write!(f, "Word list {} is invalid: {source}", path.display())
+            }
+            DictionaryError::InvalidFrequency { path, reason } => {
+                write!(f, "Frequency list {} is invalid: {reason}", path.display())
+            }
+            DictionaryError::NoOfficialList(size) => write!(
+                f,
+                "There are no official lists of {size} letter words, so both --guesses and --answers are required"
+            ),
+        }
+    }
+}
+
+impl Error for DictionaryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DictionaryError::Unreadable { source, .. } => Some(source),
+            DictionaryError::InvalidWord { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct Dictionary {
     pub all_words: Vec<Word>,
     pub potential_solns: Vec<Word>,
 }
 
-pub fn get_all_words() -> Vec<Word> {
-    let all_words = get_words(ALL_WORDS);
-    let solutions = get_words(SOLUTIONS);
+impl Dictionary {
+    pub fn guess_index(&self, word: &Word) -> Option<usize> {
+        self.all_words.iter().position(|w| w == word)
+    }
 
-    all_words
-        .chain(solutions)
-        .unique()
-        .sorted()
-        .map(|w| Word::new(&w))
-        .collect()
+    /// A stable hash of both word lists, used to tell when cached data is stale.
+    pub fn fingerprint(&self) -> u64 {
+        const WORD_END: u8 = u8::MAX - 1;
+        const LIST_END: u8 = u8::MAX;
+
+        let bytes = [&self.all_words, &self.potential_solns]
+            .into_iter()
+            .flat_map(|words| {
+                words
+                    .iter()
+                    .flat_map(|word| word.letters().iter().copied().chain([WORD_END]))
+                    .chain([LIST_END])
+            });
+
+        fnv1a(bytes)
+    }
+
+    /// Loads the given word lists. Any list not given defaults to the official list,
+    /// which is built in for five letter words only. Every answer may also be guessed.
+    pub fn load(
+        size: usize,
+        guesses: Option<&Path>,
+        answers: Option<&Path>,
+    ) -> Result<Dictionary, DictionaryError> {
+        let guesses = match guesses {
+            Some(path) => read_words(path, size)?,
+            None => official_words(&OFFICIAL_GUESSES, size)?,
+        };
+        let potential_solns = match answers {
+            Some(path) => read_words(path, size)?,
+            None => official_words(&OFFICIAL_ANSWERS, size)?,
+        };
+
+        let all_words = guesses
+            .into_iter()
+            .chain(potential_solns.iter().cloned())
+            .unique()
+            .sorted()
+            .collect();
+
+        Ok(Dictionary {
+            all_words,
+            potential_solns,
+        })
+    }
+
+    /// Parses a word that may be played as a guess.
+    pub fn parse_guess(&self, value: &str) -> Result<Word, WordError> {
+        self.parse_from(value, &self.all_words)
+    }
+
+    /// Parses a word that may be the answer to a game.
+    pub fn parse_soln(&self, value: &str) -> Result<Word, WordError> {
+        self.parse_from(value, &self.potential_solns)
+    }
+
+    fn parse_from(&self, value: &str, words: &[Word]) -> Result<Word, WordError> {
+        let word = parse_word(value, self.size())?;
+        if !words.contains(&word) {
+            return Err(WordError::NotInDictionary(word.value()));
+        }
+
+        Ok(word)
+    }
+
+    /// The number of letters in each word.
+    pub fn size(&self) -> usize {
+        self.potential_solns
+            .first()
+            .map_or(DEFAULT_SIZE, |w| w.len())
+    }
 }
 
-pub fn get_soln_words() -> Vec<Word> {
-    get_words(SOLUTIONS).map(|w| Word::new(&w)).collect()
+/// How often each word is used, relative to the others. Common words are more
+/// likely to be the answer, which matters most when the answer list is unknown.
+#[derive(Debug, Clone, Default)]
+pub struct Priors {
+    frequencies: HashMap<String, f64>,
 }
 
-fn get_words(path: &str) -> impl Iterator<Item = String> {
-    let data = fs::read_to_string(path).expect("Unable to read file");
-    let json = serde_json::from_str(&data).expect("JSON was not well-formatted");
+impl Priors {
+    /// Reads a JSON object of words to frequencies, or a plain list with a word and
+    /// its frequency on each line. Words of other lengths are allowed and ignored.
+    pub fn load(path: &Path) -> Result<Priors, DictionaryError> {
+        let data = read_file(path)?;
+        let frequencies =
+            parse_frequencies(&data).map_err(|reason| DictionaryError::InvalidFrequency {
+                path: path.to_path_buf(),
+                reason,
+            })?;
 
-    if let Value::Array(vector) = json {
-        return vector.into_iter().map(|value| match value {
-            Value::String(word) => word,
-            _ => panic!("JSON element was not a valid string"),
+        if frequencies.is_empty() {
+            return Err(DictionaryError::Empty(path.to_path_buf()));
+        }
+
+        Ok(Priors { frequencies })
+    }
+
+    /// The weight of each of `words`. Words without a frequency are given that of the
+    /// rarest word listed, so that they are unlikely but never ruled out.
+    pub fn weights(&self, words: &[Word]) -> Vec<f64> {
+        let rarest = self
+            .frequencies
+            .values()
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+
+        words
+            .iter()
+            .map(|word| {
+                self.frequencies
+                    .get(&word.value())
+                    .copied()
+                    .unwrap_or(rarest)
+            })
+            .collect()
+    }
+}
+
+/// The FNV-1a hash of `bytes`, which unlike the standard library's hashers is stable
+/// from one run to the next.
+pub fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    bytes.into_iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+/// Parses a word, checking that it has the expected number of letters.
+pub fn parse_word(value: &str, size: usize) -> Result<Word, WordError> {
+    let word = Word::try_from(value)?;
+    if word.len() != size {
+        let word = value.to_string();
+        return Err(WordError::WrongLength {
+            word,
+            expected: size,
         });
     }
 
-    panic!("JSON was not a valid vector")
+    Ok(word)
+}
+
+fn official_words(words: &[Word], size: usize) -> Result<Vec<Word>, DictionaryError> {
+    if size != DEFAULT_SIZE {
+        return Err(DictionaryError::NoOfficialList(size));
+    }
+
+    Ok(words.to_vec())
+}
+
+fn read_file(path: &Path) -> Result<String, DictionaryError> {
+    fs::read_to_string(path).map_err(|source| DictionaryError::Unreadable {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Reads the words in the file at `path`, keeping only the first of any repeats.
+fn read_words(path: &Path, size: usize) -> Result<Vec<Word>, DictionaryError> {
+    let data = read_file(path)?;
+    let words = parse_words(&data).map_err(|reason| DictionaryError::Malformed {
+        path: path.to_path_buf(),
+        reason,
+    })?;
+
+    if words.is_empty() {
+        return Err(DictionaryError::Empty(path.to_path_buf()));
+    }
+
+    // Repeated answers would otherwise count twice towards every partition
+    let words: Vec<Word> = words
+        .iter()
+        .map(|value| parse_word(value, size))
+        .collect::<Result<_, _>>()
+        .map_err(|source| DictionaryError::InvalidWord {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    Ok(words.into_iter().unique().collect())
+}
+
+/// Reads either a JSON array of strings or a plain list with one word per line.
+fn parse_words(data: &str) -> Result<Vec<String>, String> {
+    if !data.trim_start().starts_with(['[', '{']) {
+        let lines = data.lines().map(str::trim).filter(|line| !line.is_empty());
+        return Ok(lines.map(String::from).collect());
+    }
+
+    let json: Value = serde_json::from_str(data).map_err(|e| e.to_string())?;
+    let Value::Array(vector) = json else {
+        return Err("expected a JSON array".to_string());
+    };
+
+    vector
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| match value {
+            Value::String(word) => Ok(word),
+            other => Err(format!("element {i} is {other}, not a string")),
+        })
+        .collect()
+}
+
+/// Reads either a JSON object of words to frequencies or lines of a word and its frequency.
+fn parse_frequencies(data: &str) -> Result<HashMap<String, f64>, String> {
+    let pairs: Vec<(String, f64)> = if data.trim_start().starts_with('{') {
+        let json: Value = serde_json::from_str(data).map_err(|e| e.to_string())?;
+        let Value::Object(object) = json else {
+            return Err("expected a JSON object".to_string());
+        };
+
+        object
+            .into_iter()
+            .map(|(word, value)| match value.as_f64() {
+                Some(frequency) => Ok((word, frequency)),
+                None => Err(format!("the frequency of {word} is {value}, not a number")),
+            })
+            .collect::<Result<_, _>>()?
+    } else {
+        data.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (word, frequency) = line
+                    .split_once(|c: char| c.is_whitespace() || c == ',')
+                    .ok_or_else(|| format!("expected a word and a frequency but found '{line}'"))?;
+                let frequency = frequency.trim().parse().map_err(|_| {
+                    format!(
+                        "the frequency of {word} is '{}', not a number",
+                        frequency.trim()
+                    )
+                })?;
+                Ok((word.to_string(), frequency))
+            })
+            .collect::<Result<_, String>>()?
+    };
+
+    pairs
+        .into_iter()
+        .map(|(word, frequency)| {
+            if !(frequency.is_finite() && frequency > 0.0) {
+                return Err(format!("the frequency of {word} must be positive"));
+            }
+            Ok((word.to_ascii_uppercase(), frequency))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::fixtures::dictionary;
+    use rstest::rstest;
+    use std::collections::hash_map::DefaultHasher;
+    use std::env;
+    use std::hash::{Hash, Hasher};
+
+    #[rstest]
+    #[case("tower", Ok("TOWER".parse().unwrap()))]
+    #[case("SOARE", Ok("SOARE".parse().unwrap()))]
+    #[case("POWERS", Err(WordError::WrongLength { word: "POWERS".into(), expected: 5 }))]
+    #[case("P0WER", Err(WordError::InvalidCharacter { word: "P0WER".into(), character: '0' }))]
+    #[case("LOWER", Err(WordError::NotInDictionary("LOWER".into())))]
+    fn parse_guess__for_input__validates(
+        dictionary: Dictionary,
+        #[case] value: &str,
+        #[case] expected: Result<Word, WordError>,
+    ) {
+        // Act
+        let actual = dictionary.parse_guess(value);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    fn parse_soln__for_guess_only_word__errors(dictionary: Dictionary) {
+        // Act
+        let actual = dictionary.parse_soln("SOARE");
+
+        // Assert
+        assert_eq!(Err(WordError::NotInDictionary("SOARE".into())), actual);
+    }
+
+    #[rstest]
+    fn load__for_official_lists__loads_sorted_words() {
+        // Act
+        let actual = Dictionary::load(DEFAULT_SIZE, None, None).unwrap();
+
+        // Assert
+        assert_eq!(12972, actual.all_words.len());
+        assert_eq!(2315, actual.potential_solns.len());
+        assert!(actual.all_words.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[rstest]
+    #[case("[\"tower\", \"ROWER\"]")]
+    #[case("tower\n\n  ROWER  \r\n")]
+    fn load__for_custom_lists__reads_either_format(#[case] contents: &str) {
+        // Arrange
+        let path = temp_file("doddle-answers", contents);
+
+        // Act
+        let actual = Dictionary::load(DEFAULT_SIZE, None, Some(&path));
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        let expected: Vec<Word> = vec!["TOWER".parse().unwrap(), "ROWER".parse().unwrap()];
+        let dictionary = actual.unwrap();
+        assert_eq!(expected, dictionary.potential_solns);
+        // The official guesses, which exclude the official answers, plus the custom answers
+        assert_eq!(10657 + 2, dictionary.all_words.len());
+    }
+
+    #[rstest]
+    fn load__for_other_length_without_lists__errors() {
+        // Act
+        let actual = Dictionary::load(6, None, None);
+
+        // Assert
+        assert!(matches!(actual, Err(DictionaryError::NoOfficialList(6))));
+    }
+
+    #[rstest]
+    fn load__for_missing_file__errors() {
+        // Arrange
+        let path = env::temp_dir().join("doddle-missing-guesses.json");
+
+        // Act
+        let actual = Dictionary::load(DEFAULT_SIZE, Some(&path), None);
+
+        // Assert
+        let error = actual.err().unwrap();
+        assert!(matches!(error, DictionaryError::Unreadable { .. }));
+        assert!(error.to_string().contains("doddle-missing-guesses.json"));
+    }
+
+    #[rstest]
+    #[case("[\"TOWER\",", "EOF while parsing")]
+    #[case("{\"words\": []}", "expected a JSON array")]
+    #[case("[\"TOWER\", 42]", "element 1 is 42, not a string")]
+    fn parse_words__for_malformed_json__explains(#[case] data: &str, #[case] expected: &str) {
+        // Act
+        let actual = parse_words(data);
+
+        // Assert
+        assert!(actual.unwrap_err().contains(expected));
+    }
+
+    #[rstest]
+    #[case("", "does not contain any words")]
+    #[case("TOWER\nPOWERS\n", "'POWERS' does not have 5 letters")]
+    fn read_words__for_bad_contents__errors(#[case] contents: &str, #[case] expected: &str) {
+        // Arrange
+        let path = env::temp_dir().join(format!("doddle-bad-{}.txt", contents.len()));
+        fs::write(&path, contents).unwrap();
+
+        // Act
+        let actual = read_words(&path, DEFAULT_SIZE);
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        let message = actual.unwrap_err().to_string();
+        assert!(message.contains(expected), "{message}");
+    }
+
+    #[rstest]
+    #[case("TOWER\nPOWER\ntower\nROWER\nPOWER\n")]
+    #[case("[\"TOWER\", \"POWER\", \"tower\", \"ROWER\", \"POWER\"]")]
+    fn read_words__with_duplicates__keeps_first_of_each(#[case] contents: &str) {
+        // Arrange
+        let path = temp_file("doddle-duplicates", contents);
+
+        // Act
+        let actual = read_words(&path, DEFAULT_SIZE).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        let expected: Vec<Word> = vec![
+            "TOWER".parse().unwrap(),
+            "POWER".parse().unwrap(),
+            "ROWER".parse().unwrap(),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("{\"tower\": 20, \"POWER\": 5.5, \"TOWERS\": 1}")]
+    #[case("tower 20\n\nPOWER\t5.5\nTOWERS,1\n")]
+    fn priors_load__for_either_format__weights_words(
+        dictionary: Dictionary,
+        #[case] contents: &str,
+    ) {
+        // Arrange
+        let path = temp_file("doddle-priors", contents);
+
+        // Act
+        let actual = Priors::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        // ROWER is missing, so it is as rare as the rarest word listed
+        let weights = actual.unwrap().weights(&dictionary.potential_solns);
+        assert_eq!(vec![20.0, 1.0, 5.5], weights);
+    }
+
+    #[rstest]
+    #[case(
+        "TOWER 20\nPOWER\n",
+        "expected a word and a frequency but found 'POWER'"
+    )]
+    #[case("TOWER lots\n", "the frequency of TOWER is 'lots', not a number")]
+    #[case("TOWER 0\n", "the frequency of TOWER must be positive")]
+    #[case(
+        "{\"TOWER\": \"20\"}",
+        "the frequency of TOWER is \"20\", not a number"
+    )]
+    #[case("\n", "does not contain any words")]
+    fn priors_load__for_bad_contents__errors(#[case] contents: &str, #[case] expected: &str) {
+        // Arrange
+        let path = temp_file("doddle-bad-priors", contents);
+
+        // Act
+        let actual = Priors::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        let message = actual.unwrap_err().to_string();
+        assert!(message.contains(expected), "{message}");
+    }
+
+    /// Writes `contents` to a temporary file named after a hash of them, so cases never collide.
+    fn temp_file(prefix: &str, contents: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        contents.hash(&mut hasher);
+        let path = env::temp_dir().join(format!("{prefix}-{:x}.txt", hasher.finish()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
 }
@@ -0,0 +1,189 @@
+use itertools::Itertools;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result};
+
+use crate::guess::GuessMetrics;
+use crate::reporting::ConsoleReporter;
+use crate::scoring::{self, score_to_str, Score};
+use crate::word::Word;
+
+/// The most words listed for any one partition before the rest are summarised.
+const MAX_WORDS_SHOWN: usize = 10;
+
+/// How a guess partitions the remaining candidates, as tabulated in the README.
+pub struct Explanation {
+    pub guess: Word,
+    pub partitions: BTreeMap<Score, Vec<Word>>,
+}
+
+impl Explanation {
+    pub fn new(guess: Word, candidates: &[Word]) -> Explanation {
+        let mut partitions: BTreeMap<Score, Vec<Word>> = BTreeMap::new();
+        for candidate in candidates {
+            let score = scoring::score(&guess, candidate);
+            partitions.entry(score).or_default().push(candidate.clone());
+        }
+
+        Explanation { guess, partitions }
+    }
+
+    pub fn num_candidates(&self) -> usize {
+        self.partitions.values().map(Vec::len).sum()
+    }
+
+    pub fn metrics(&self) -> GuessMetrics {
+        let mut histogram = vec![0_u32; scoring::num_scores(self.guess.len())];
+        for (&score, words) in &self.partitions {
+            histogram[score as usize] = words.len() as u32;
+        }
+
+        GuessMetrics::from_histogram(self.guess.clone(), &histogram)
+    }
+
+    pub fn to_json(&self) -> Value {
+        let partitions: Map<String, Value> = self
+            .partitions
+            .iter()
+            .map(|(&score, words)| (score_to_str(score, self.guess.len()), json!(words)))
+            .collect();
+
+        json!({
+            "guess": self.guess,
+            "candidates": self.num_candidates(),
+            "metrics": self.metrics(),
+            "partitions": partitions,
+        })
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let size = self.guess.len();
+        let width = ConsoleReporter::column_width(size);
+        let padding = " ".repeat(width - size);
+        let line = "-".repeat(width + 2);
+        let num_candidates = self.num_candidates();
+
+        writeln!(
+            f,
+            "| {:<width$} | {:<width$} | Partition Size | Probability | Possible Words",
+            "Guess", "Score"
+        )?;
+        writeln!(
+            f,
+            "|{line}|{line}|----------------|-------------|---------------"
+        )?;
+
+        for (&score, words) in &self.partitions {
+            let ternary = score_to_str(score, size);
+            let guess = ConsoleReporter::prettify(&self.guess.value(), &ternary);
+            let pretty_ternary = ConsoleReporter::prettify(&ternary, &ternary);
+            let probability = words.len() as f64 / num_candidates as f64;
+
+            let shown = summarise(words);
+
+            writeln!(
+                f,
+                "| {guess}{padding} | {pretty_ternary}{padding} | {: >14} | {probability: >11.2} | {{ {shown} }}",
+                words.len()
+            )?;
+        }
+
+        let metrics = self.metrics();
+        writeln!(f)?;
+        writeln!(f, "Candidates: {num_candidates}")?;
+        writeln!(f, "Shannon bits: {:.2}", metrics.entropy)?;
+        write!(f, "Largest bucket: {}", metrics.largest_bucket)
+    }
+}
+
+/// Lists the first few `words`, then how many more there are.
+pub fn summarise(words: &[Word]) -> String {
+    let shown = words.iter().take(MAX_WORDS_SHOWN).join(", ");
+    if words.len() > MAX_WORDS_SHOWN {
+        format!("{shown} and {} more", words.len() - MAX_WORDS_SHOWN)
+    } else {
+        shown
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use float_cmp::assert_approx_eq;
+    use rstest::{fixture, rstest};
+
+    #[rstest]
+    fn metrics__for_readme_example__match(explanation: Explanation) {
+        // Act
+        let actual = explanation.metrics();
+
+        // Assert
+        assert_eq!(20, explanation.num_candidates());
+        assert_eq!(8, explanation.partitions.len());
+        assert_approx_eq!(f64, 2.83, actual.entropy, epsilon = 0.005);
+        assert_eq!(5, actual.largest_bucket);
+    }
+
+    #[rstest]
+    fn display__for_readme_example__tabulates_partitions(explanation: Explanation) {
+        // Arrange
+        colored::control::set_override(false);
+
+        // Act
+        let actual = format!("{explanation}");
+
+        // Assert
+        let expected_row =
+            "| THURL | 02000 |              5 |        0.25 | { SHADE, SHAKE, SHAME, SHAPE, SHAVE }";
+        assert!(actual.lines().any(|line| line == expected_row), "{actual}");
+        assert!(actual.ends_with("Shannon bits: 2.83\nLargest bucket: 5"));
+    }
+
+    #[rstest]
+    fn to_json__for_readme_example__lists_partitions(explanation: Explanation) {
+        // Act
+        let actual = explanation.to_json();
+
+        // Assert
+        assert_eq!("THURL", actual["guess"]);
+        assert_eq!(20, actual["candidates"]);
+        assert_eq!(5, actual["metrics"]["largest_bucket"]);
+        assert_eq!(5, actual["partitions"]["02000"].as_array().unwrap().len());
+        assert_eq!("SHADE", actual["partitions"]["02000"][0]);
+    }
+
+    #[rstest]
+    fn display__for_large_partition__summarises_words() {
+        // Arrange
+        colored::control::set_override(false);
+        let candidates: Vec<Word> = (b'A'..=b'L')
+            .map(|c| format!("{}OOOO", c as char).parse().unwrap())
+            .collect();
+        let sut = Explanation::new("XXXXX".parse().unwrap(), &candidates);
+
+        // Act
+        let actual = format!("{sut}");
+
+        // Assert
+        assert!(actual.contains("AOOOO, BOOOO"));
+        assert!(actual.contains("JOOOO and 2 more }"));
+    }
+
+    #[fixture]
+    fn explanation() -> Explanation {
+        let candidates: Vec<Word> = [
+            "SNAKE", "SPACE", "SPADE", "SCALE", "SCARE", "SNARE", "SPARE", "SHADE", "SHAKE",
+            "SHAME", "SHAPE", "SHAVE", "SHALE", "SHARE", "SHARK", "SKATE", "STAGE", "STAVE",
+            "SLATE", "STALE",
+        ]
+        .iter()
+        .map(|w| w.parse().unwrap())
+        .collect();
+
+        Explanation::new("THURL".parse().unwrap(), &candidates)
+    }
+}
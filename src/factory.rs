@@ -1,50 +1,132 @@
-use crate::dictionary;
 use crate::dictionary::Dictionary;
 use crate::guess::EntropyAlgorithm;
+use crate::guess::ExpectedSizeAlgorithm;
 use crate::guess::MinimaxAlgorithm;
+use crate::guess::MostPartsAlgorithm;
+use crate::multiboard::{MultiSolver, SolveBoards};
 use crate::reporting::NullReporter;
-use crate::reporting::{ConsoleReporter, Reporter};
+use crate::reporting::{ConsoleReporter, Format, JsonReporter, Reporter};
 use crate::solver::Solve;
 use crate::solver::Solver;
-use crate::word::Word;
+use crate::solver::SolverOptions;
 
 use clap::ValueEnum;
+use std::error::Error;
 
 #[derive(ValueEnum, Debug, Clone, Copy)]
 pub enum SolverType {
     Entropy,
     Minimax,
+    /// Minimises the number of answers expected to remain
+    ExpectedSize,
+    /// Maximises the number of distinct scores a guess could receive
+    MostParts,
+    /// Searches the entropy solver's best candidates for optimal play
+    Optimal,
+    /// Ranks the entropy solver's best candidates by looking two guesses ahead
+    Lookahead,
 }
 
-pub fn get_solver(solver: SolverType) -> Box<dyn Solve> {
-    let reporter = get_reporter(true);
-
-    let all_words: Vec<Word> = dictionary::get_all_words();
-    let potential_solns: Vec<Word> = dictionary::get_soln_words();
-    let dictionary = Dictionary {
-        all_words,
-        potential_solns,
-    };
+pub fn get_solver(
+    solver: SolverType,
+    dictionary: Dictionary,
+    options: SolverOptions,
+    format: Option<Format>,
+) -> Box<dyn Solve> {
+    let reporter = get_reporter(format);
 
     match solver {
         SolverType::Entropy => {
             let algorithm = EntropyAlgorithm;
-            let solver = Solver::new(algorithm, reporter, dictionary);
+            let solver = Solver::new(algorithm, reporter, dictionary, options);
             Box::new(solver)
         }
         SolverType::Minimax => {
             let algorithm = MinimaxAlgorithm;
-            let solver = Solver::new(algorithm, reporter, dictionary);
+            let solver = Solver::new(algorithm, reporter, dictionary, options);
+            Box::new(solver)
+        }
+        SolverType::ExpectedSize => {
+            let algorithm = ExpectedSizeAlgorithm;
+            let solver = Solver::new(algorithm, reporter, dictionary, options);
+            Box::new(solver)
+        }
+        SolverType::MostParts => {
+            let algorithm = MostPartsAlgorithm;
+            let solver = Solver::new(algorithm, reporter, dictionary, options);
+            Box::new(solver)
+        }
+        SolverType::Optimal => {
+            let algorithm = EntropyAlgorithm;
+            let options = SolverOptions {
+                search: Some(options.search.unwrap_or_default()),
+                ..options
+            };
+            let solver = Solver::new(algorithm, reporter, dictionary, options);
             Box::new(solver)
         }
+        SolverType::Lookahead => {
+            let algorithm = EntropyAlgorithm;
+            let options = SolverOptions {
+                lookahead: Some(options.lookahead.unwrap_or_default()),
+                ..options
+            };
+            let solver = Solver::new(algorithm, reporter, dictionary, options);
+            Box::new(solver)
+        }
+    }
+}
+
+pub fn get_multi_solver(
+    solver: SolverType,
+    dictionary: Dictionary,
+    options: SolverOptions,
+    format: Option<Format>,
+) -> Result<Box<dyn SolveBoards>, Box<dyn Error>> {
+    let reporter = get_reporter(format);
+
+    // Progress is reported per game, not by the solver evaluating each board
+    let null_reporter = Box::new(NullReporter);
+
+    match solver {
+        SolverType::Entropy => {
+            let algorithm = EntropyAlgorithm;
+            let solver = Solver::new(algorithm, null_reporter, dictionary, options);
+            Ok(Box::new(MultiSolver::new(solver, reporter)))
+        }
+        SolverType::Minimax => {
+            let algorithm = MinimaxAlgorithm;
+            let solver = Solver::new(algorithm, null_reporter, dictionary, options);
+            Ok(Box::new(MultiSolver::new(solver, reporter)))
+        }
+        SolverType::ExpectedSize => {
+            let algorithm = ExpectedSizeAlgorithm;
+            let solver = Solver::new(algorithm, null_reporter, dictionary, options);
+            Ok(Box::new(MultiSolver::new(solver, reporter)))
+        }
+        SolverType::MostParts => {
+            let algorithm = MostPartsAlgorithm;
+            let solver = Solver::new(algorithm, null_reporter, dictionary, options);
+            Ok(Box::new(MultiSolver::new(solver, reporter)))
+        }
+        SolverType::Optimal | SolverType::Lookahead => {
+            let name = solver.to_possible_value().unwrap();
+            Err(format!(
+                "The {} solver cannot play several boards at once",
+                name.get_name()
+            )
+            .into())
+        }
     }
 }
 
-pub fn get_reporter(show_progress: bool) -> Box<dyn Reporter> {
-    if show_progress {
-        Box::new(ConsoleReporter)
-    } else {
-        Box::new(NullReporter)
+/// Reports each game in the given format, or reports nothing without one.
+pub fn get_reporter(format: Option<Format>) -> Box<dyn Reporter> {
+    match format {
+        Some(Format::Table) => Box::new(ConsoleReporter),
+        Some(Format::Share(reporter)) => Box::new(reporter),
+        Some(Format::Json) => Box::new(JsonReporter),
+        None => Box::new(NullReporter),
     }
 }
 
@@ -56,8 +138,8 @@ mod tests {
 
     use crate::{
         boards::{Scoreboard, ScoreboardRow},
-        scoring::MAX_SCORE,
-        word::Word,
+        scoring::max_score,
+        word::{Word, DEFAULT_SIZE},
     };
 
     use super::*;
@@ -67,37 +149,153 @@ mod tests {
         // Arrange
 
         // Act
-        let reporter = get_reporter(false);
+        let reporter = get_reporter(None);
 
         reporter.report_failure(&scoreboard);
     }
 
     #[rstest]
-    fn get_solver__for_minimax__returns() {
+    fn get_solver__for_minimax__returns(dictionary: Dictionary) {
         // Arrange
-        let soln: Word = "SNAKE".into();
+        let soln: Word = "SNAKE".parse().unwrap();
 
         // Act
-        let solver = get_solver(SolverType::Minimax);
-        let scoreboard = solver.solve(&soln, soln.clone());
+        let solver = get_solver(
+            SolverType::Minimax,
+            dictionary,
+            SolverOptions::default(),
+            Some(Format::Table),
+        );
+        let scoreboard = solver.solve(&soln, Some(soln.clone()));
 
         // Assert
         assert_eq!(1, scoreboard.unwrap().len());
     }
 
     #[rstest]
-    fn get_solver__for_entropy__returns() {
+    fn get_solver__for_entropy__returns(dictionary: Dictionary) {
         // Arrange
-        let soln: Word = "SNAKE".into();
+        let soln: Word = "SNAKE".parse().unwrap();
 
         // Act
-        let solver = get_solver(SolverType::Entropy);
-        let scoreboard = solver.solve(&soln, soln.clone());
+        let solver = get_solver(
+            SolverType::Entropy,
+            dictionary,
+            SolverOptions::default(),
+            Some(Format::Table),
+        );
+        let scoreboard = solver.solve(&soln, Some(soln.clone()));
 
         // Assert
         assert_eq!(1, scoreboard.unwrap().len());
     }
 
+    #[rstest]
+    #[case(SolverType::Optimal, "optimal")]
+    #[case(SolverType::Lookahead, "lookahead")]
+    fn get_multi_solver__for_single_board_solver__errors(
+        dictionary: Dictionary,
+        #[case] solver_type: SolverType,
+        #[case] name: &str,
+    ) {
+        // Act
+        let actual = get_multi_solver(solver_type, dictionary, SolverOptions::default(), None);
+
+        // Assert
+        let Err(error) = actual else {
+            panic!("expected the {name} solver to be rejected");
+        };
+        assert!(error.to_string().contains(name), "{error}");
+    }
+
+    #[rstest]
+    fn get_multi_solver__in_hard_mode__never_breaks_the_rules(
+        dictionary: Dictionary,
+        #[values(SolverType::Entropy, SolverType::Minimax)] solver_type: SolverType,
+    ) {
+        // Arrange
+        let options = SolverOptions {
+            hard_mode: true,
+            ..Default::default()
+        };
+        let solver = get_multi_solver(solver_type, dictionary, options, None).unwrap();
+        // Each pair shares most of its hints, so a guess can use them all
+        let games = [
+            ["TOWER", "POWER"],
+            ["CRANE", "CRATE"],
+            ["BASIC", "BASIN"],
+            ["JAUNT", "HAUNT"],
+        ];
+
+        for solns in games {
+            // Act
+            let solns: Vec<Word> = solns.iter().map(|soln| soln.parse().unwrap()).collect();
+            let scoreboards = solver.solve(&solns, Some("SALET".parse().unwrap()));
+
+            // Assert
+            for scoreboard in scoreboards.unwrap() {
+                assert!(scoreboard.is_solved());
+                for (i, row) in scoreboard.rows.iter().enumerate() {
+                    let previous = Scoreboard {
+                        rows: scoreboard.rows[..i].to_vec(),
+                    };
+                    assert!(
+                        previous.permits(&row.guess),
+                        "{} broke hard mode",
+                        row.guess
+                    );
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    fn get_solver__in_hard_mode__never_breaks_the_rules(
+        #[values(
+            SolverType::Entropy,
+            SolverType::Minimax,
+            SolverType::ExpectedSize,
+            SolverType::MostParts
+        )]
+        solver_type: SolverType,
+    ) {
+        // Arrange
+        let options = SolverOptions {
+            hard_mode: true,
+            ..Default::default()
+        };
+        let solver = get_solver(
+            solver_type,
+            Dictionary::load(DEFAULT_SIZE, None, None).unwrap(),
+            options,
+            None,
+        );
+        let solns = ["MUMMY", "JAUNT", "BASIC", "RIPER", "WATCH", "FOYER"];
+
+        for soln in solns {
+            // Act
+            let soln: Word = soln.parse().unwrap();
+            let scoreboard = solver.solve(&soln, Some("SALET".parse().unwrap())).unwrap();
+
+            // Assert
+            for (i, row) in scoreboard.rows.iter().enumerate() {
+                let previous = Scoreboard {
+                    rows: scoreboard.rows[..i].to_vec(),
+                };
+                assert!(
+                    previous.permits(&row.guess),
+                    "{} broke hard mode",
+                    row.guess
+                );
+            }
+        }
+    }
+
+    #[fixture]
+    fn dictionary() -> Dictionary {
+        Dictionary::load(DEFAULT_SIZE, None, None).unwrap()
+    }
+
     #[fixture]
     fn scoreboard() -> Scoreboard {
         // Arrange
@@ -105,25 +303,25 @@ mod tests {
 
         let row1 = ScoreboardRow {
             n: 1,
-            soln: "SNAKE".into(),
-            guess: "SOARE".into(),
+            soln: Some("SNAKE".parse().unwrap()),
+            guess: "SOARE".parse().unwrap(),
             score: 42,
             num_left: 123,
         };
 
         let row2 = ScoreboardRow {
             n: 1,
-            soln: "SNAKE".into(),
-            guess: "CLINT".into(),
+            soln: Some("SNAKE".parse().unwrap()),
+            guess: "CLINT".parse().unwrap(),
             score: 142,
             num_left: 3,
         };
 
         let row3 = ScoreboardRow {
             n: 1,
-            soln: "SNAKE".into(),
-            guess: "SNAKE".into(),
-            score: MAX_SCORE as u8,
+            soln: Some("SNAKE".parse().unwrap()),
+            guess: "SNAKE".parse().unwrap(),
+            score: max_score(5),
             num_left: 1,
         };
 
@@ -0,0 +1,57 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_TMP: AtomicUsize = AtomicUsize::new(0);
+
+/// Replaces the file at `path` with `contents` in one step, by writing to a temporary file
+/// beside it and renaming that into place. Concurrent readers therefore never see a partial
+/// file, and the temporary name is unique to this process and call so concurrent writers
+/// never clobber each other's half-written data.
+pub fn atomic_write(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let suffix = format!(
+        "{}.{}.tmp",
+        process::id(),
+        NEXT_TMP.fetch_add(1, Ordering::Relaxed)
+    );
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".");
+    tmp_name.push(suffix);
+    let tmp_path = path.with_file_name(tmp_name);
+
+    let result = fs::write(&tmp_path, contents).and_then(|_| fs::rename(&tmp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+
+    result
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn atomic_write__over_existing_file__replaces_it_and_leaves_no_temporary() {
+        // Arrange
+        let dir = env::temp_dir().join(format!("doddle-atomic-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("data.json");
+        fs::write(&path, "old").unwrap();
+
+        // Act
+        atomic_write(&path, "new").unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        let num_files = fs::read_dir(&dir).unwrap().count();
+        fs::remove_dir_all(&dir).unwrap();
+
+        // Assert
+        assert_eq!("new", contents);
+        assert_eq!(1, num_files);
+    }
+}
@@ -0,0 +1,46 @@
+use rstest::fixture;
+
+use crate::dictionary::Dictionary;
+use crate::word::Word;
+
+/// Three answers ending in -OWER, with two openers that cannot be the answer.
+#[fixture]
+pub fn dictionary() -> Dictionary {
+    let all_words = vec![
+        "SALET".parse().unwrap(),
+        "TOWER".parse().unwrap(),
+        "SOARE".parse().unwrap(),
+        "ROWER".parse().unwrap(),
+        "POWER".parse().unwrap(),
+    ];
+
+    let potential_solns = vec![
+        "TOWER".parse().unwrap(),
+        "ROWER".parse().unwrap(),
+        "POWER".parse().unwrap(),
+    ];
+
+    Dictionary {
+        all_words,
+        potential_solns,
+    }
+}
+
+/// Four -ILL answers that only KAPOW, which cannot be the answer, splits apart in one guess.
+#[fixture]
+pub fn skill_family() -> Dictionary {
+    let potential_solns: Vec<Word> = vec![
+        "SKILL".parse().unwrap(),
+        "SPILL".parse().unwrap(),
+        "STILL".parse().unwrap(),
+        "SWILL".parse().unwrap(),
+    ];
+
+    let mut all_words = vec!["KAPOW".parse().unwrap()];
+    all_words.extend(potential_solns.clone());
+
+    Dictionary {
+        all_words,
+        potential_solns,
+    }
+}
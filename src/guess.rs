@@ -1,11 +1,28 @@
 use float_cmp::approx_eq;
+use serde::Serialize;
 
 use crate::word::Word;
 use std::cmp::Ordering;
 
-pub trait Algorithm {
-    type TGuess: Ord + Into<Word>;
+pub trait Algorithm: Sync {
+    type TGuess: Ord + Into<Word> + Send;
+
+    /// Identifies the algorithm in cached results.
+    const NAME: &'static str;
+
     fn make_guess(&self, guess: &Word, num_solns: usize, histogram: &[u32]) -> Self::TGuess;
+
+    /// Makes a guess when some solutions are more likely than others, where `weights`
+    /// holds the total prior weight of the solutions in each bucket of `histogram`.
+    fn make_weighted_guess(
+        &self,
+        guess: &Word,
+        histogram: &[u32],
+        _weights: &[f64],
+    ) -> Self::TGuess {
+        let num_solns = histogram.iter().sum::<u32>() as usize;
+        self.make_guess(guess, num_solns, histogram)
+    }
 }
 
 #[derive(Debug)]
@@ -13,6 +30,7 @@ pub struct EntropyGuess {
     word: Word,
     entropy: f64,
     is_potential_soln: bool,
+    soln_probability: f64,
 }
 
 impl EntropyGuess {
@@ -21,9 +39,15 @@ impl EntropyGuess {
             word,
             entropy,
             is_potential_soln,
+            soln_probability: 0.0,
         }
     }
 
+    /// The bits of information the guess is expected to give.
+    pub fn entropy(&self) -> f64 {
+        self.entropy
+    }
+
     fn cmp(&self, other: &Self) -> Ordering {
         if !approx_eq!(f64, self.entropy, other.entropy, epsilon = 1e-9) {
             // Reverse comparison: High entropy is a lower guess
@@ -39,7 +63,8 @@ impl EntropyGuess {
             };
         }
 
-        Ordering::Equal
+        // Of two potential solutions, the more likely answer is the lower guess
+        other.soln_probability.total_cmp(&self.soln_probability)
     }
 }
 
@@ -53,20 +78,36 @@ pub struct EntropyAlgorithm;
 
 impl Algorithm for EntropyAlgorithm {
     type TGuess = EntropyGuess;
-    fn make_guess(&self, guess: &Word, num_solns: usize, histogram: &[u32]) -> EntropyGuess {
+    const NAME: &'static str = "entropy";
+
+    fn make_guess(&self, guess: &Word, _num_solns: usize, histogram: &[u32]) -> EntropyGuess {
+        // Without priors, every solution is equally likely
+        let weights: Vec<f64> = histogram.iter().map(|&count| count as f64).collect();
+        self.make_weighted_guess(guess, histogram, &weights)
+    }
+
+    fn make_weighted_guess(
+        &self,
+        guess: &Word,
+        histogram: &[u32],
+        weights: &[f64],
+    ) -> EntropyGuess {
         let word = guess.clone();
-        let is_potential_soln = *histogram.last().unwrap() == 1;
+        let is_potential_soln = *histogram.last().unwrap() > 0;
+        let total: f64 = weights.iter().sum();
 
-        let entropy: f64 = histogram
+        let entropy: f64 = weights
             .iter()
-            .filter(|&&count| count > 0)
-            .map(|&count| {
-                let probability = count as f64 / num_solns as f64;
+            .filter(|&&weight| weight > 0.0)
+            .map(|&weight| {
+                let probability = weight / total;
                 probability * probability.log2()
             })
             .sum();
 
-        EntropyGuess::new(word, -entropy, is_potential_soln)
+        let mut guess = EntropyGuess::new(word, -entropy, is_potential_soln);
+        guess.soln_probability = weights.last().unwrap() / total;
+        guess
     }
 }
 
@@ -78,7 +119,7 @@ impl PartialEq for EntropyGuess {
 }
 impl PartialOrd for EntropyGuess {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+        Some(Ord::cmp(self, other))
     }
     fn lt(&self, other: &Self) -> bool {
         matches!(self.cmp(other), Ordering::Less)
@@ -143,9 +184,11 @@ pub struct MinimaxAlgorithm;
 
 impl Algorithm for MinimaxAlgorithm {
     type TGuess = MinimaxGuess;
+    const NAME: &'static str = "minimax";
+
     fn make_guess(&self, guess: &Word, _num_solns: usize, histogram: &[u32]) -> MinimaxGuess {
         let word = guess.clone();
-        let is_potential_soln = *histogram.last().unwrap() == 1;
+        let is_potential_soln = *histogram.last().unwrap() > 0;
 
         let largest_bucket = histogram.iter().copied().max().unwrap_or(0_u32);
 
@@ -160,7 +203,7 @@ impl PartialEq for MinimaxGuess {
 }
 impl PartialOrd for MinimaxGuess {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+        Some(Ord::cmp(self, other))
     }
     fn lt(&self, other: &Self) -> bool {
         matches!(self.cmp(other), Ordering::Less)
@@ -183,21 +226,263 @@ impl Ord for MinimaxGuess {
     }
 }
 
+#[derive(Debug)]
+pub struct ExpectedSizeGuess {
+    word: Word,
+    expected_size: f64,
+    is_potential_soln: bool,
+}
+
+impl ExpectedSizeGuess {
+    fn new(word: Word, expected_size: f64, is_potential_soln: bool) -> ExpectedSizeGuess {
+        ExpectedSizeGuess {
+            word,
+            expected_size,
+            is_potential_soln,
+        }
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        if !approx_eq!(f64, self.expected_size, other.expected_size, epsilon = 1e-9) {
+            return self.expected_size.total_cmp(&other.expected_size);
+        }
+
+        if self.is_potential_soln != other.is_potential_soln {
+            return if self.is_potential_soln {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl From<ExpectedSizeGuess> for Word {
+    fn from(item: ExpectedSizeGuess) -> Self {
+        item.word
+    }
+}
+
+/// Minimises the number of solutions expected to remain after each guess: the sum
+/// of the squared bucket sizes over the number of solutions.
+pub struct ExpectedSizeAlgorithm;
+
+impl Algorithm for ExpectedSizeAlgorithm {
+    type TGuess = ExpectedSizeGuess;
+    const NAME: &'static str = "expected-size";
+
+    fn make_guess(&self, guess: &Word, num_solns: usize, histogram: &[u32]) -> ExpectedSizeGuess {
+        let word = guess.clone();
+        let is_potential_soln = *histogram.last().unwrap() > 0;
+
+        let sum_of_squares: f64 = histogram
+            .iter()
+            .map(|&count| count as f64 * count as f64)
+            .sum();
+
+        ExpectedSizeGuess::new(word, sum_of_squares / num_solns as f64, is_potential_soln)
+    }
+}
+
+impl PartialEq for ExpectedSizeGuess {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Equal)
+    }
+}
+impl PartialOrd for ExpectedSizeGuess {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+    fn lt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Less)
+    }
+    fn le(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Equal | Ordering::Less)
+    }
+    fn gt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Greater)
+    }
+    fn ge(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Equal | Ordering::Greater)
+    }
+}
+impl Eq for ExpectedSizeGuess {}
+
+impl Ord for ExpectedSizeGuess {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cmp(other)
+    }
+}
+
+#[derive(Debug)]
+pub struct MostPartsGuess {
+    word: Word,
+    num_parts: usize,
+    is_potential_soln: bool,
+}
+
+impl MostPartsGuess {
+    fn new(word: Word, num_parts: usize, is_potential_soln: bool) -> MostPartsGuess {
+        MostPartsGuess {
+            word,
+            num_parts,
+            is_potential_soln,
+        }
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.num_parts != other.num_parts {
+            // Reverse comparison: More parts is a lower guess
+            return other.num_parts.cmp(&self.num_parts);
+        }
+
+        if self.is_potential_soln != other.is_potential_soln {
+            return if self.is_potential_soln {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl From<MostPartsGuess> for Word {
+    fn from(item: MostPartsGuess) -> Self {
+        item.word
+    }
+}
+
+/// Maximises the number of distinct scores each guess could receive.
+pub struct MostPartsAlgorithm;
+
+impl Algorithm for MostPartsAlgorithm {
+    type TGuess = MostPartsGuess;
+    const NAME: &'static str = "most-parts";
+
+    fn make_guess(&self, guess: &Word, _num_solns: usize, histogram: &[u32]) -> MostPartsGuess {
+        let word = guess.clone();
+        let is_potential_soln = *histogram.last().unwrap() > 0;
+
+        let num_parts = histogram.iter().filter(|&&count| count > 0).count();
+
+        MostPartsGuess::new(word, num_parts, is_potential_soln)
+    }
+}
+
+impl PartialEq for MostPartsGuess {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Equal)
+    }
+}
+impl PartialOrd for MostPartsGuess {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+    fn lt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Less)
+    }
+    fn le(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Equal | Ordering::Less)
+    }
+    fn gt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Greater)
+    }
+    fn ge(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Equal | Ordering::Greater)
+    }
+}
+impl Eq for MostPartsGuess {}
+
+impl Ord for MostPartsGuess {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cmp(other)
+    }
+}
+
+/// How a guess partitions the remaining solutions, for showing alongside suggestions.
+#[derive(Debug, Clone, Serialize)]
+pub struct GuessMetrics {
+    pub word: Word,
+
+    /// Expected information, in Shannon bits.
+    pub entropy: f64,
+
+    /// The most solutions that could remain after this guess.
+    pub largest_bucket: u32,
+
+    /// The number of distinct scores this guess could receive.
+    pub num_buckets: usize,
+
+    /// The number of solutions expected to remain after this guess.
+    pub expected_size: f64,
+
+    pub is_potential_soln: bool,
+}
+
+impl GuessMetrics {
+    pub fn from_histogram(word: Word, histogram: &[u32]) -> GuessMetrics {
+        let num_solns: u32 = histogram.iter().sum();
+        let buckets = histogram.iter().filter(|&&count| count > 0);
+
+        let mut entropy = 0.0;
+        let mut expected_size = 0.0;
+        for &count in buckets.clone() {
+            let probability = count as f64 / num_solns as f64;
+            entropy -= probability * probability.log2();
+            expected_size += probability * count as f64;
+        }
+
+        GuessMetrics {
+            word,
+            entropy,
+            largest_bucket: histogram.iter().copied().max().unwrap_or(0),
+            num_buckets: buckets.count(),
+            expected_size,
+            is_potential_soln: *histogram.last().unwrap() > 0,
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod tests {
 
-    use crate::scoring::MAX_SCORE;
+    use crate::scoring::num_scores;
 
     use super::*;
     use float_cmp::assert_approx_eq;
     use rstest::{fixture, rstest};
 
+    #[test]
+    fn from_histogram__for_thurl__matches_readme() {
+        // Arrange
+        // The partition sizes from the README's worked example of THURL
+        let mut histogram = vec![0_u32; num_scores(5)];
+        for (i, count) in [3, 1, 3, 5, 1, 2, 3, 2].into_iter().enumerate() {
+            histogram[i] = count;
+        }
+        let word: Word = "THURL".parse().unwrap();
+
+        // Act
+        let actual = GuessMetrics::from_histogram(word, &histogram);
+
+        // Assert
+        assert_approx_eq!(f64, 2.8282, actual.entropy, epsilon = 1e-4);
+        assert_eq!(5, actual.largest_bucket);
+        assert_eq!(8, actual.num_buckets);
+        assert_approx_eq!(f64, 62.0 / 20.0, actual.expected_size, epsilon = 1e-9);
+        assert!(!actual.is_potential_soln);
+    }
+
     #[test]
     fn test_minimax_guess_where_largest_bucket_differs() {
         // Arrange
-        let word1 = Word::new("SNAKE");
-        let word2 = Word::new("SHARK");
+        let word1: Word = "SNAKE".parse().unwrap();
+        let word2: Word = "SHARK".parse().unwrap();
         let is_potential_soln = true;
         let guess1 = MinimaxGuess::new(word1, 20, is_potential_soln);
         let guess2 = MinimaxGuess::new(word2, 25, is_potential_soln);
@@ -218,8 +503,8 @@ mod tests {
     #[test]
     fn test_minimax_guess_where_common_differs() {
         // Arrange
-        let word1 = Word::new("SNAKE");
-        let word2 = Word::new("SHARK");
+        let word1: Word = "SNAKE".parse().unwrap();
+        let word2: Word = "SHARK".parse().unwrap();
         let is_potential_soln = true;
         let guess1 = MinimaxGuess::new(word1, 25, is_potential_soln);
         let guess2 = MinimaxGuess::new(word2, 25, !is_potential_soln);
@@ -236,8 +521,8 @@ mod tests {
     #[test]
     fn test_minimax_guess_where_all_same_is_equal() {
         // Arrange
-        let word1 = Word::new("SLATE");
-        let word2 = Word::new("FREAK");
+        let word1: Word = "SLATE".parse().unwrap();
+        let word2: Word = "FREAK".parse().unwrap();
         let is_potential_soln = true;
         let guess1 = MinimaxGuess::new(word1, 25, is_potential_soln);
         let guess2 = MinimaxGuess::new(word2, 25, is_potential_soln);
@@ -255,8 +540,8 @@ mod tests {
     #[test]
     fn test_entropy_guess_where_entropy_differs() {
         // Arrange
-        let word1 = Word::new("SNAKE");
-        let word2 = Word::new("SHARK");
+        let word1: Word = "SNAKE".parse().unwrap();
+        let word2: Word = "SHARK".parse().unwrap();
         let is_potential_soln = true;
         let guess1 = EntropyGuess::new(word1, 12.1, is_potential_soln);
         let guess2 = EntropyGuess::new(word2, 10.0, is_potential_soln);
@@ -277,8 +562,8 @@ mod tests {
     #[test]
     fn test_entropy_guess_where_common_differs() {
         // Arrange
-        let word1 = Word::new("SNAKE");
-        let word2 = Word::new("SHARK");
+        let word1: Word = "SNAKE".parse().unwrap();
+        let word2: Word = "SHARK".parse().unwrap();
         let is_potential_soln = true;
         let entropy = 10.0;
         let guess1 = EntropyGuess::new(word1, entropy, is_potential_soln);
@@ -296,8 +581,8 @@ mod tests {
     #[test]
     fn test_entropy_guess_where_all_same_is_equal() {
         // Arrange
-        let word1 = Word::new("SNAKE");
-        let word2 = Word::new("SHARK");
+        let word1: Word = "SNAKE".parse().unwrap();
+        let word2: Word = "SHARK".parse().unwrap();
         let is_potential_soln = true;
         let entropy = 10.0;
         let guess1 = EntropyGuess::new(word1, entropy, is_potential_soln);
@@ -316,7 +601,7 @@ mod tests {
     fn make_guess__for_minimax__makes(histogram_potential_soln: Vec<u32>) {
         // Arrange
         let sut = MinimaxAlgorithm;
-        let guess: Word = "SOARE".into();
+        let guess: Word = "SOARE".parse().unwrap();
         let num_solns: u32 = histogram_potential_soln.iter().sum();
 
         // Act
@@ -331,7 +616,7 @@ mod tests {
     fn make_guess__for_entropy__makes(histogram_uncommon_word: Vec<u32>) {
         // Arrange
         let sut = EntropyAlgorithm;
-        let guess: Word = "SOARE".into();
+        let guess: Word = "SOARE".parse().unwrap();
         let num_solns: u32 = histogram_uncommon_word.iter().sum();
 
         // Act
@@ -342,15 +627,195 @@ mod tests {
         assert!(!actual.is_potential_soln);
     }
 
+    #[rstest]
+    fn make_weighted_guess__for_entropy__weights_probabilities(histogram_uncommon_word: Vec<u32>) {
+        // Arrange
+        let sut = EntropyAlgorithm;
+        let guess: Word = "SOARE".parse().unwrap();
+        let mut weights = vec![0_f64; num_scores(5)];
+        weights[..4].copy_from_slice(&[6.0, 1.0, 0.5, 0.5]);
+
+        // Act
+        let actual = sut.make_weighted_guess(&guess, &histogram_uncommon_word, &weights);
+
+        // Assert
+        // Probabilities of 3/4, 1/8, 1/16 and 1/16 rather than a quarter each
+        assert_approx_eq!(f64, 1.1862781, actual.entropy, epsilon = 1e-6);
+        assert!(!actual.is_potential_soln);
+    }
+
+    #[rstest]
+    fn make_weighted_guess__for_minimax__ignores_weights(histogram_potential_soln: Vec<u32>) {
+        // Arrange
+        let sut = MinimaxAlgorithm;
+        let guess: Word = "SOARE".parse().unwrap();
+        let weights = vec![1_f64; num_scores(5)];
+
+        // Act
+        let actual = sut.make_weighted_guess(&guess, &histogram_potential_soln, &weights);
+
+        // Assert
+        assert_eq!(7, actual.largest_bucket);
+        assert!(actual.is_potential_soln);
+    }
+
+    #[rstest]
+    fn make_weighted_guess__for_equal_entropy__prefers_likelier_answer() {
+        // Arrange
+        let sut = EntropyAlgorithm;
+        let mut histogram = vec![0_u32; num_scores(5)];
+        histogram[0] = 1;
+        *histogram.last_mut().unwrap() = 1;
+
+        let mut likely = vec![0_f64; num_scores(5)];
+        likely[0] = 1.0;
+        *likely.last_mut().unwrap() = 3.0;
+
+        let mut unlikely = vec![0_f64; num_scores(5)];
+        unlikely[0] = 3.0;
+        *unlikely.last_mut().unwrap() = 1.0;
+
+        // Act
+        let common = sut.make_weighted_guess(&"TOWER".parse().unwrap(), &histogram, &likely);
+        let rare = sut.make_weighted_guess(&"ROWER".parse().unwrap(), &histogram, &unlikely);
+
+        // Assert
+        assert_approx_eq!(f64, common.entropy, rare.entropy, epsilon = 1e-9);
+        assert!(common < rare);
+    }
+
+    #[test]
+    fn test_expected_size_guess_where_expected_size_differs() {
+        // Arrange
+        let word1: Word = "SNAKE".parse().unwrap();
+        let word2: Word = "SHARK".parse().unwrap();
+        let is_potential_soln = true;
+        let guess1 = ExpectedSizeGuess::new(word1, 3.1, is_potential_soln);
+        let guess2 = ExpectedSizeGuess::new(word2, 4.0, !is_potential_soln);
+
+        // Act
+        let is_better = guess1 < guess2;
+        let is_worse = guess1 > guess2;
+
+        // Assert
+        assert!(is_better);
+        assert!(!is_worse);
+    }
+
+    #[test]
+    fn test_expected_size_guess_where_common_differs() {
+        // Arrange
+        let word1: Word = "SNAKE".parse().unwrap();
+        let word2: Word = "SHARK".parse().unwrap();
+        let is_potential_soln = true;
+        let guess1 = ExpectedSizeGuess::new(word1, 3.1, !is_potential_soln);
+        let guess2 = ExpectedSizeGuess::new(word2, 3.1, is_potential_soln);
+
+        // Act
+        let is_better = guess1 < guess2;
+        let is_worse = guess1 > guess2;
+
+        // Assert
+        assert!(!is_better);
+        assert!(is_worse);
+    }
+
+    #[test]
+    fn test_most_parts_guess_where_num_parts_differs() {
+        // Arrange
+        let word1: Word = "SNAKE".parse().unwrap();
+        let word2: Word = "SHARK".parse().unwrap();
+        let is_potential_soln = true;
+        let guess1 = MostPartsGuess::new(word1, 8, !is_potential_soln);
+        let guess2 = MostPartsGuess::new(word2, 4, is_potential_soln);
+
+        // Act
+        let is_better = guess1 < guess2;
+        let is_worse = guess1 > guess2;
+
+        // Assert
+        assert!(is_better);
+        assert!(!is_worse);
+    }
+
+    #[test]
+    fn test_most_parts_guess_where_all_same_is_equal() {
+        // Arrange
+        let word1: Word = "SLATE".parse().unwrap();
+        let word2: Word = "FREAK".parse().unwrap();
+        let is_potential_soln = true;
+        let guess1 = MostPartsGuess::new(word1, 4, is_potential_soln);
+        let guess2 = MostPartsGuess::new(word2, 4, is_potential_soln);
+
+        // Act
+        let is_better = guess1 < guess2;
+        let is_worse = guess1 > guess2;
+
+        // Assert
+        assert_eq!(guess1, guess2);
+        assert!(!is_better);
+        assert!(!is_worse);
+    }
+
+    #[rstest]
+    fn make_guess__for_expected_size__makes(histogram_potential_soln: Vec<u32>) {
+        // Arrange
+        let sut = ExpectedSizeAlgorithm;
+        let guess: Word = "SOARE".parse().unwrap();
+        let num_solns: u32 = histogram_potential_soln.iter().sum();
+
+        // Act
+        let actual = sut.make_guess(&guess, num_solns as usize, &histogram_potential_soln);
+
+        // Assert
+        // (1 + 49 + 1 + 1) / 10
+        assert_approx_eq!(f64, 5.2, actual.expected_size, epsilon = 1e-9);
+        assert!(actual.is_potential_soln);
+    }
+
+    #[rstest]
+    fn make_guess__for_most_parts__makes(histogram_uncommon_word: Vec<u32>) {
+        // Arrange
+        let sut = MostPartsAlgorithm;
+        let guess: Word = "SOARE".parse().unwrap();
+        let num_solns: u32 = histogram_uncommon_word.iter().sum();
+
+        // Act
+        let actual = sut.make_guess(&guess, num_solns as usize, &histogram_uncommon_word);
+
+        // Assert
+        assert_eq!(4, actual.num_parts);
+        assert!(!actual.is_potential_soln);
+    }
+
+    #[test]
+    fn make_guess__for_thurl__matches_metrics() {
+        // Arrange
+        let mut histogram = vec![0_u32; num_scores(5)];
+        for (i, count) in [3, 1, 3, 5, 1, 2, 3, 2].into_iter().enumerate() {
+            histogram[i] = count;
+        }
+        let word: Word = "THURL".parse().unwrap();
+
+        // Act
+        let expected_size = ExpectedSizeAlgorithm.make_guess(&word, 20, &histogram);
+        let most_parts = MostPartsAlgorithm.make_guess(&word, 20, &histogram);
+
+        // Assert
+        // (9 + 1 + 9 + 25 + 1 + 4 + 9 + 4) / 20
+        assert_approx_eq!(f64, 3.1, expected_size.expected_size, epsilon = 1e-9);
+        assert_eq!(8, most_parts.num_parts);
+    }
+
     #[fixture]
     fn histogram_potential_soln() -> Vec<u32> {
         // Arrange
-        let mut rows = vec![0_u32; MAX_SCORE + 1];
+        let mut rows = vec![0_u32; num_scores(5)];
 
         rows[0] = 1;
         rows[1] = 7;
         rows[2] = 1;
-        rows[MAX_SCORE] = 1;
+        rows[num_scores(5) - 1] = 1;
 
         rows
     }
@@ -358,7 +823,7 @@ mod tests {
     #[fixture]
     fn histogram_uncommon_word() -> Vec<u32> {
         // Arrange
-        let mut rows = vec![0_u32; MAX_SCORE + 1];
+        let mut rows = vec![0_u32; num_scores(5)];
 
         rows[0] = 1;
         rows[1] = 1;
@@ -0,0 +1,244 @@
+use float_cmp::approx_eq;
+
+use crate::guess::Algorithm;
+use crate::scoring;
+use crate::solver::Solver;
+use crate::word::Word;
+
+/// Looks two guesses ahead. Greedy entropy can pick a guess whose partitions are
+/// each hard to split further, so the solver's best few candidates are instead
+/// ranked by their own information plus the expected information of the best
+/// guess that could follow them.
+#[derive(Debug, Clone, Copy)]
+pub struct Lookahead {
+    /// Number of the heuristic's best guesses to look beyond.
+    pub top_k: usize,
+}
+
+impl Default for Lookahead {
+    fn default() -> Self {
+        Lookahead { top_k: 10 }
+    }
+}
+
+impl Lookahead {
+    /// Picks the most informative of the solver's `top_k` guesses, two guesses ahead.
+    /// Returns `None` when two or fewer answers remain, as one of them should be guessed.
+    pub fn best_guess<T: Algorithm>(
+        &self,
+        solver: &Solver<T>,
+        guesses: &[usize],
+        potential_solns: &[usize],
+    ) -> Option<Word> {
+        if potential_solns.len() <= 2 {
+            return None;
+        }
+
+        let mut best: Option<(Word, f64)> = None;
+        for guess in solver.ranked_guesses(guesses, potential_solns, self.top_k) {
+            let information = self.information(solver, guesses, guess, potential_solns);
+            let guess = &solver.dictionary().all_words[guess];
+
+            // Ties go to the candidate the heuristic ranks higher
+            let is_better = match &best {
+                Some((_, best)) => {
+                    information > *best && !approx_eq!(f64, information, *best, epsilon = 1e-9)
+                }
+                None => true,
+            };
+            if is_better {
+                best = Some((guess.clone(), information));
+            }
+        }
+
+        best.map(|(guess, _)| guess)
+    }
+
+    /// The bits of information from `guess`, given as a position in `all_words`, plus the
+    /// expected bits from the solver's best guess after it, in Shannon bits.
+    pub fn information<T: Algorithm>(
+        &self,
+        solver: &Solver<T>,
+        guesses: &[usize],
+        guess: usize,
+        potential_solns: &[usize],
+    ) -> f64 {
+        let dictionary = solver.dictionary();
+        let guess_word = &dictionary.all_words[guess];
+        let max_score = scoring::max_score(guess_word.len());
+        let total_weight = solver.weight(potential_solns);
+        let mut information = solver.entropy(guess, potential_solns);
+
+        for (score, partition) in solver.partition(guess_word, potential_solns) {
+            if score == max_score || partition.len() < 2 {
+                continue;
+            }
+
+            // In hard mode, the next guess must also use the hints from this score
+            // The top ranked guess is as informative as `best_guess`, which only differs by
+            // preferring an answer when two are left, and comes with its position
+            let next_guesses = solver.remaining_guesses(guesses, guess_word, score);
+            let next_guess = solver.ranked_guesses(&next_guesses, &partition, 1)[0];
+
+            let probability = solver.weight(&partition) / total_weight;
+            information += probability * solver.entropy(next_guess, &partition);
+        }
+
+        information
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::dictionary::{Dictionary, Priors};
+    use crate::fixtures::skill_family;
+    use crate::guess::EntropyAlgorithm;
+    use crate::reporting::NullReporter;
+    use crate::solver::SolverOptions;
+    use float_cmp::assert_approx_eq;
+    use rstest::{fixture, rstest};
+    use std::{env, fs};
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    fn information__when_two_guesses_split_every_answer__is_all_bits(
+        solver: Solver<EntropyAlgorithm>,
+        #[case] guess: usize,
+    ) {
+        // Arrange
+        let sut = Lookahead::default();
+        let guesses: Vec<usize> = (0..5).collect();
+
+        // Act
+        let actual = sut.information(&solver, &guesses, guess, &[0, 1, 2, 3]);
+
+        // Assert
+        // SKILL only gives 0.81 bits, but KAPOW then splits the other three apart
+        assert_approx_eq!(f64, 2.0, actual, epsilon = 1e-9);
+    }
+
+    #[rstest]
+    fn information__in_hard_mode__only_follows_with_permitted_guesses(
+        solver: Solver<EntropyAlgorithm>,
+    ) {
+        // Arrange
+        let options = SolverOptions {
+            hard_mode: true,
+            ..Default::default()
+        };
+        let solver = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            solver.dictionary().clone(),
+            options,
+        );
+        let sut = Lookahead::default();
+        let guesses: Vec<usize> = (0..5).collect();
+
+        // Act
+        let actual = sut.information(&solver, &guesses, 1, &[0, 1, 2, 3]);
+
+        // Assert
+        // After SKILL, KAPOW breaks hard mode, and another -ILL word only splits off itself
+        assert_approx_eq!(f64, 1.5, actual, epsilon = 1e-9);
+    }
+
+    #[rstest]
+    fn information__with_priors__weights_each_answer(solver: Solver<EntropyAlgorithm>) {
+        // Arrange
+        let path = env::temp_dir().join("doddle-lookahead-priors.txt");
+        fs::write(&path, "SKILL 2\nSPILL 1\nSTILL 1\nSWILL 4\n").unwrap();
+        let options = SolverOptions {
+            priors: Some(Priors::load(&path).unwrap()),
+            ..Default::default()
+        };
+        fs::remove_file(&path).unwrap();
+
+        let solver = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            solver.dictionary().clone(),
+            options,
+        );
+        let sut = Lookahead::default();
+        let guesses: Vec<usize> = (0..5).collect();
+
+        // Act
+        let actual = sut.information(&solver, &guesses, 1, &[0, 1, 2, 3]);
+
+        // Assert
+        // KAPOW still splits every answer apart, so all the bits in the priors are found
+        let expected: f64 = [0.25_f64, 0.125, 0.125, 0.5]
+            .iter()
+            .map(|p| -p * p.log2())
+            .sum();
+        assert_approx_eq!(f64, expected, actual, epsilon = 1e-9);
+    }
+
+    #[rstest]
+    fn best_guess__for_tie__keeps_heuristic_choice(solver: Solver<EntropyAlgorithm>) {
+        // Arrange
+        let sut = Lookahead::default();
+        let guesses: Vec<usize> = (0..5).collect();
+
+        // Act
+        let actual = sut.best_guess(&solver, &guesses, &[0, 1, 2, 3]);
+
+        // Assert
+        assert_eq!(Some("KAPOW".parse().unwrap()), actual);
+    }
+
+    #[rstest]
+    fn best_guess__for_two_answers__returns_none(solver: Solver<EntropyAlgorithm>) {
+        // Arrange
+        let sut = Lookahead::default();
+        let guesses: Vec<usize> = (0..5).collect();
+
+        // Act
+        let actual = sut.best_guess(&solver, &guesses, &[0, 1]);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[rstest]
+    fn run__with_lookahead__solves(solver: Solver<EntropyAlgorithm>) {
+        // Arrange
+        let options = SolverOptions {
+            lookahead: Some(Lookahead::default()),
+            ..Default::default()
+        };
+        let sut = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            solver.dictionary().clone(),
+            options,
+        );
+
+        // Act
+        let actual = sut.run(&"SPILL".parse().unwrap(), Some("SKILL".parse().unwrap()));
+
+        // Assert
+        let guesses: Vec<String> = actual
+            .unwrap()
+            .rows
+            .iter()
+            .map(|r| r.guess.value())
+            .collect();
+        assert_eq!(vec!["SKILL", "KAPOW", "SPILL"], guesses);
+    }
+
+    #[fixture]
+    fn solver(skill_family: Dictionary) -> Solver<EntropyAlgorithm> {
+        Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            skill_family,
+            SolverOptions::default(),
+        )
+    }
+}
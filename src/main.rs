@@ -1,37 +1,935 @@
+use crate::absurdle::AbsurdleSolver;
+use crate::benchmark::Benchmark;
+use crate::boards::Scoreboard;
+use crate::dictionary::{Dictionary, DictionaryError, Priors};
+use crate::explain::Explanation;
 use crate::factory::SolverType;
-use crate::word::Word;
-use clap::Parser;
+use crate::lookahead::Lookahead;
+use crate::matrix::MatrixSource;
+use crate::play::Game;
+use crate::reporting::{ConsoleReporter, Format, JsonReporter};
+use crate::search::{Objective, SearchOptions};
+use crate::share::{ShareGrid, ShareReporter, Theme};
+use crate::solver::SolverOptions;
+use crate::word::{Word, WordError, DEFAULT_SIZE};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde_json::{json, Map, Value};
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
 
+mod absurdle;
+mod analysis;
+mod benchmark;
 mod boards;
+mod cache;
 mod dictionary;
+mod explain;
 mod factory;
+mod files;
+#[cfg(test)]
+mod fixtures;
 mod guess;
+mod lookahead;
+mod matrix;
+mod multiboard;
+mod play;
 mod reporting;
 mod scoring;
+mod search;
+mod share;
 mod solver;
+mod tree;
 mod word;
 
-fn main() {
-    let args = Args::parse();
-    let soln = Word::new(&args.answer);
-    let guess = Word::new(&args.guess);
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    let solver = factory::get_solver(args.solver);
-    solver.solve(&soln, guess);
+    match run(cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {error}");
+            ExitCode::FAILURE
+        }
+    }
 }
 
-/// Simple program to greet a person
+fn run(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Solve(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let soln = dictionary.parse_soln(&args.answer)?;
+            let guess = args.solver.opening_guess(&dictionary)?;
+
+            let format = args.output.format(args.solver.hard);
+            let solver = factory::get_solver(
+                args.solver.solver,
+                dictionary,
+                args.solver.options()?,
+                Some(format),
+            );
+            solver.solve(&soln, guess);
+        }
+        Command::Interactive(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let guess = args.solver.opening_guess(&dictionary)?;
+
+            let format = args.output.format(args.solver.hard);
+            let solver = factory::get_solver(
+                args.solver.solver,
+                dictionary,
+                args.solver.options()?,
+                Some(format),
+            );
+            solver.solve_interactive(guess);
+        }
+        Command::Benchmark(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let guess = args.solver.opening_guess(&dictionary)?;
+            let solns = args.solns(&dictionary)?;
+
+            // Every game scores the same pairs of words so always precompute them
+            let mut options = args.solver.options()?;
+            if let MatrixSource::None = options.matrix {
+                options.matrix = MatrixSource::Memory;
+            }
+
+            let solver = factory::get_solver(args.solver.solver, dictionary, options, None);
+            if args.output.is_json() {
+                let benchmark = Benchmark::run(solver.as_ref(), &solns, guess);
+                println!("{}", benchmark.to_json());
+            } else {
+                println!("Benchmarking {:?} solver...", args.solver.solver);
+                let benchmark = Benchmark::run(solver.as_ref(), &solns, guess);
+                println!("{benchmark}");
+            }
+        }
+        Command::Multi(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let guess = args.solver.opening_guess(&dictionary)?;
+            let solns = args
+                .words
+                .iter()
+                .map(|answer| dictionary.parse_soln(answer))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // Every board is written together as one document, rather than one per board
+            let format = (!args.output.is_json()).then_some(Format::Table);
+            let solver = factory::get_multi_solver(
+                args.solver.solver,
+                dictionary,
+                args.solver.options()?,
+                format,
+            )?;
+
+            let now = Instant::now();
+            let scoreboards = if solns.is_empty() {
+                solver.solve_interactive(args.boards, guess)
+            } else {
+                solver.solve(&solns, guess)
+            };
+
+            if args.output.is_json() {
+                let boards: Vec<Value> = scoreboards
+                    .iter()
+                    .flatten()
+                    .map(|scoreboard| JsonReporter::report(scoreboard, None))
+                    .collect();
+                let elapsed_ms = now.elapsed().as_secs_f64() * 1000.0;
+                println!(
+                    "{}",
+                    json!({ "solved": scoreboards.is_some(), "elapsed_ms": elapsed_ms, "boards": boards })
+                );
+            }
+        }
+        Command::Absurdle(args) => {
+            let dictionary = args.solver.dictionary()?;
+
+            if args.solve {
+                // Unlike the other commands, only fix the opening guess when asked to
+                let guess = match &args.solver.guess {
+                    Some(guess) => Some(dictionary.parse_guess(guess)?),
+                    None => None,
+                };
+                let options = args.solver.options()?;
+                let solver =
+                    AbsurdleSolver::new(dictionary, options, args.solver.top_k, args.solver.depth);
+
+                let Some(scoreboard) = solver.solve(guess) else {
+                    return Err(format!("No line wins within {} guesses", args.solver.depth).into());
+                };
+
+                factory::get_reporter(Some(args.output.format(args.solver.hard)))
+                    .print(&scoreboard);
+
+                if !args.output.is_json() {
+                    let qualifier = if solver.is_exhaustive() {
+                        "the fewest possible"
+                    } else {
+                        "the fewest using the top candidates at each step"
+                    };
+                    println!(
+                        "\nGuarantees a win in {} guesses, {qualifier}.",
+                        scoreboard.len()
+                    );
+                }
+            } else if args.auto {
+                let guess = args.solver.opening_guess(&dictionary)?;
+                let solver = factory::get_solver(
+                    args.solver.solver,
+                    dictionary,
+                    args.solver.options()?,
+                    Some(args.output.format(args.solver.hard)),
+                );
+                if args.output.is_json() {
+                    solver.solve_adversarial(guess);
+                } else {
+                    println!(
+                        "Playing {:?} solver against Absurdle...",
+                        args.solver.solver
+                    );
+                    let scoreboard = solver.solve_adversarial(guess);
+                    if let Some(scoreboard) = scoreboard {
+                        println!("Solved in {} guesses.", scoreboard.len());
+                    }
+                }
+            } else {
+                let solver = factory::get_solver(
+                    args.solver.solver,
+                    dictionary,
+                    args.solver.options()?,
+                    None,
+                );
+                let scoreboard = solver.host_absurdle();
+                if let Some(scoreboard) = scoreboard.filter(|_| args.output.is_json()) {
+                    println!("{}", JsonReporter::report(&scoreboard, None));
+                }
+            }
+        }
+        Command::Explain(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let Some(guess) = args.solver.opening_guess(&dictionary)? else {
+                return Err("Choose a guess to explain with --guess".into());
+            };
+
+            let candidates = args.candidates(&dictionary)?;
+            if candidates.is_empty() {
+                return Err("No answers are consistent with the guesses played".into());
+            }
+
+            let explanation = Explanation::new(guess, &candidates);
+            if args.output.is_json() {
+                println!("{}", explanation.to_json());
+            } else {
+                println!("{explanation}");
+            }
+        }
+        Command::Tree(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let guess = args.solver.opening_guess(&dictionary)?;
+
+            let mut options = args.solver.options()?;
+            if let MatrixSource::None = options.matrix {
+                options.matrix = MatrixSource::Memory;
+            }
+
+            let solver = factory::get_solver(args.solver.solver, dictionary, options, None);
+            let tree = solver.decision_tree(guess);
+            let output = match args.format {
+                TreeFormat::Json => serde_json::to_string_pretty(&tree.to_json())?,
+                TreeFormat::Dot => tree.to_dot(),
+            };
+
+            // Keep stdout clean for the tree itself unless it is going to a file
+            match args.output {
+                Some(path) => {
+                    fs::write(&path, output)?;
+                    println!("{tree}");
+                }
+                None => {
+                    println!("{output}");
+                    eprintln!("{tree}");
+                }
+            }
+        }
+        Command::Openers(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let solver_type = args.solver.solver;
+            let mut options = args.solver.options()?;
+            if let MatrixSource::None = options.matrix {
+                options.matrix = MatrixSource::Memory;
+            }
+
+            let solver = factory::get_solver(solver_type, dictionary, options, None);
+            if args.output.is_json() {
+                let openers = solver.openers(args.number);
+                let opening_guess = solver.opening_guess();
+                let replies: Map<String, Value> = solver
+                    .replies(&opening_guess)
+                    .into_iter()
+                    .map(|(score, reply)| {
+                        (
+                            scoring::score_to_str(score, opening_guess.len()),
+                            json!(reply),
+                        )
+                    })
+                    .collect();
+                println!(
+                    "{}",
+                    json!({ "openers": openers, "opening": opening_guess, "replies": replies })
+                );
+            } else {
+                println!("Ranking opening guesses for the {solver_type:?} solver...");
+                factory::get_reporter(Some(Format::Table))
+                    .report_suggestions(&solver.openers(args.number));
+
+                let opening_guess = solver.opening_guess();
+                let replies = solver.replies(&opening_guess);
+                println!(
+                    "The {solver_type:?} solver opens with {opening_guess}, then has a second guess for each of the {} scores it could receive.",
+                    replies.len()
+                );
+                if let Some(path) = &args.solver.cache {
+                    println!("Saved the first and second guesses to {}.", path.display());
+                }
+            }
+        }
+        Command::Play(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let mut game = Game::random(&dictionary, args.seed, args.solver.hard);
+            let scoreboard = game.run(&mut io::stdin().lock());
+
+            // The board is shown as the game is played, so only add the grid to share
+            match (scoreboard, args.output.format(args.solver.hard)) {
+                (Some(scoreboard), Format::Share(share)) => {
+                    println!("\n{}", share.report(&scoreboard))
+                }
+                (Some(scoreboard), Format::Json) => {
+                    println!("{}", JsonReporter::report(&scoreboard, None))
+                }
+                _ => {}
+            }
+        }
+        Command::Analyse(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let soln = dictionary.parse_soln(&args.answer)?;
+            let guesses = args.guesses(&dictionary, &soln)?;
+
+            let solver =
+                factory::get_solver(args.solver.solver, dictionary, args.solver.options()?, None);
+            let analysis = solver.analyse(&soln, &guesses);
+            if args.output.is_json() {
+                println!("{}", analysis.to_json());
+            } else {
+                println!("{analysis}");
+            }
+        }
+        Command::Grid(args) => {
+            let dictionary = args.solver.dictionary()?;
+            let grid: ShareGrid = match &args.input {
+                Some(path) => fs::read_to_string(path)?.parse()?,
+                None => io::read_to_string(io::stdin())?.parse()?,
+            };
+            if grid.size() != dictionary.size() {
+                return Err(format!(
+                    "The grid has {} squares per row but the words have {} letters",
+                    grid.size(),
+                    dictionary.size()
+                )
+                .into());
+            }
+
+            let scores: Vec<String> = grid
+                .scores
+                .iter()
+                .map(|&score| scoring::score_to_str(score, grid.size()))
+                .collect();
+
+            match &args.answer {
+                Some(answer) => {
+                    let soln = dictionary.parse_soln(answer)?;
+                    let guesses = grid.guesses(&dictionary, &soln);
+                    if args.output.is_json() {
+                        println!("{}", json!({ "scores": scores, "guesses": guesses }));
+                    } else {
+                        for (n, (ternary, guesses)) in scores.iter().zip(guesses).enumerate() {
+                            println!(
+                                "{} {}: {} words {{ {} }}",
+                                n + 1,
+                                ConsoleReporter::prettify(ternary, ternary),
+                                guesses.len(),
+                                explain::summarise(&guesses)
+                            );
+                        }
+                    }
+                }
+                None => {
+                    let answers = grid.answers(&dictionary);
+                    if args.output.is_json() {
+                        println!("{}", json!({ "scores": scores, "answers": answers }));
+                    } else {
+                        println!(
+                            "{} answers fit the grid: {{ {} }}",
+                            answers.len(),
+                            explain::summarise(&answers)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The opening guess for five letter words, when the guesses allow it.
+const DEFAULT_OPENING: &str = "SALET";
+
+/// A rusty Wordle solver
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
-struct Args {
-    /// Name of the person to greet
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve a game for which the answer is already known
+    Solve(SolveArgs),
+
+    /// Solve a live game by entering the score Wordle gives each guess
+    Interactive(InteractiveArgs),
+
+    /// Solve every answer in the dictionary and summarise the number of guesses
+    Benchmark(BenchmarkArgs),
+
+    /// Solve several boards at once, as in Dordle, Quordle and Octordle
+    Multi(MultiArgs),
+
+    /// Play Absurdle, where the host dodges every guess for as long as it can
+    Absurdle(AbsurdleArgs),
+
+    /// Show how a guess partitions the remaining answers
+    Explain(ExplainArgs),
+
+    /// Export the full decision tree the solver follows for every answer
+    Tree(TreeArgs),
+
+    /// Rank the best opening guesses, caching the solver's first and second guesses
+    Openers(OpenersArgs),
+
+    /// Play a game yourself, guessing a hidden answer in six tries
+    Play(PlayArgs),
+
+    /// Grade each guess of a finished game against the solver's own choice
+    Analyse(AnalyseArgs),
+
+    /// Infer the guesses, or else the answers, behind a share grid pasted from Wordle
+    Grid(GridArgs),
+}
+
+#[derive(Args, Debug)]
+struct SolveArgs {
+    /// The answer to the game
+    #[arg(short, long)]
+    answer: String,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args, Debug)]
+struct InteractiveArgs {
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args, Debug)]
+struct BenchmarkArgs {
+    /// Restrict the benchmark to these answers (comma separated)
+    #[arg(short, long, value_delimiter = ',')]
+    words: Vec<String>,
+
+    /// Restrict the benchmark to the first N answers
+    #[arg(short, long)]
+    limit: Option<usize>,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+impl BenchmarkArgs {
+    fn solns(&self, dictionary: &Dictionary) -> Result<Vec<Word>, WordError> {
+        let solns = if self.words.is_empty() {
+            dictionary.potential_solns.clone()
+        } else {
+            self.words
+                .iter()
+                .map(|w| dictionary.parse_soln(w))
+                .collect::<Result<_, _>>()?
+        };
+
+        let limit = self.limit.unwrap_or(solns.len());
+        Ok(solns.into_iter().take(limit).collect())
+    }
+}
+
+#[derive(Args, Debug)]
+struct MultiArgs {
+    /// The answer on each board (comma separated). Without answers, solve a live game
+    #[arg(short, long, value_delimiter = ',')]
+    words: Vec<String>,
+
+    /// The number of boards in a live game
+    #[arg(short, long, default_value_t = 4)]
+    boards: usize,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args, Debug)]
+struct AbsurdleArgs {
+    /// Let the solver play against the host instead of playing yourself
+    #[arg(long)]
+    auto: bool,
+
+    /// Search for the shortest line that is guaranteed to win (a --top-k of 0 searches every guess)
+    #[arg(long, conflicts_with = "auto")]
+    solve: bool,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args, Debug)]
+struct ExplainArgs {
+    /// The remaining answers (comma separated), instead of every answer in the dictionary
+    #[arg(short, long, value_delimiter = ',')]
+    words: Vec<String>,
+
+    /// Narrow the answers by guesses already played, as GUESS:SCORE (e.g. SALET:00010)
+    #[arg(short, long, value_delimiter = ',')]
+    played: Vec<String>,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+impl ExplainArgs {
+    fn candidates(&self, dictionary: &Dictionary) -> Result<Vec<Word>, Box<dyn Error>> {
+        let mut candidates = if self.words.is_empty() {
+            dictionary.potential_solns.clone()
+        } else {
+            self.words
+                .iter()
+                .map(|w| dictionary.parse_guess(w))
+                .collect::<Result<_, _>>()?
+        };
+
+        for played in &self.played {
+            let Some((guess, score)) = played.split_once(':') else {
+                return Err(format!("Expected GUESS:SCORE but found '{played}'").into());
+            };
+
+            let guess = dictionary.parse_guess(guess)?;
+            let Some(score) = scoring::parse_score(score, guess.len()) else {
+                return Err(format!("Unable to parse '{score}' as a score").into());
+            };
+
+            candidates.retain(|candidate| scoring::score(&guess, candidate) == score);
+        }
+
+        Ok(candidates)
+    }
+}
+
+#[derive(Args, Debug)]
+struct TreeArgs {
+    /// The format to export the tree in
+    #[arg(short, long, default_value = "json", ignore_case = true)]
+    format: TreeFormat,
+
+    /// Write the tree to this file instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+}
+
+#[derive(Args, Debug)]
+struct PlayArgs {
+    /// Pick the same hidden answer every time for a given seed, instead of at random
+    #[arg(long)]
+    seed: Option<u64>,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args, Debug)]
+struct AnalyseArgs {
+    /// The answer to the game
     #[arg(short, long)]
     answer: String,
 
-    /// Number of times to greet
-    #[arg(short, long, default_value = "SALET")]
-    guess: String,
+    /// The guesses played, in order (comma separated)
+    #[arg(short, long, value_delimiter = ',', required = true)]
+    words: Vec<String>,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+impl AnalyseArgs {
+    /// Parses the guesses played, which must stop at the answer and, in hard mode,
+    /// use every hint revealed so far.
+    fn guesses(&self, dictionary: &Dictionary, soln: &Word) -> Result<Vec<Word>, Box<dyn Error>> {
+        let guesses = self
+            .words
+            .iter()
+            .map(|guess| dictionary.parse_guess(guess))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match guesses.iter().position(|guess| guess == soln) {
+            Some(n) if n + 1 < guesses.len() => {
+                return Err(format!("The answer was already found by guess {}", n + 1).into())
+            }
+            _ => {}
+        }
+
+        if self.solver.hard {
+            let mut scoreboard = Scoreboard::default();
+            for guess in &guesses {
+                if !scoreboard.permits(guess) {
+                    return Err(format!(
+                        "{guess} does not use every hint revealed so far, as hard mode requires"
+                    )
+                    .into());
+                }
+
+                let score = scoring::score(guess, soln);
+                scoreboard.add_row(Some(soln.clone()), guess.clone(), score, 0);
+            }
+        }
+
+        Ok(guesses)
+    }
+}
+
+#[derive(Args, Debug)]
+struct GridArgs {
+    /// The answer to the game, to list the words that could have been played for each row
+    #[arg(short, long)]
+    answer: Option<String>,
+
+    /// Read the grid from this file instead of stdin
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args, Debug)]
+struct OpenersArgs {
+    /// The number of opening guesses to rank
+    #[arg(short, long, default_value_t = 10)]
+    number: usize,
+
+    #[command(flatten)]
+    solver: SolverArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum TreeFormat {
+    Json,
+    Dot,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum OutputFormat {
+    /// A table of guesses and scores, coloured for the terminal
+    Table,
+    /// The grid of emoji squares that Wordle shares
+    Share,
+    /// A line of JSON for each result, as documented in the README
+    Json,
+}
+
+#[derive(Args, Debug)]
+struct OutputArgs {
+    /// How to write out the results. Commands without a game to share write a table instead
+    #[arg(long, default_value = "table", ignore_case = true)]
+    format: OutputFormat,
+
+    /// The background the shared grid will be pasted onto
+    #[arg(long, default_value = "dark", ignore_case = true)]
+    theme: Theme,
+
+    /// Share orange and blue squares in place of green and yellow
+    #[arg(long)]
+    high_contrast: bool,
+}
 
+impl OutputArgs {
+    fn format(&self, hard_mode: bool) -> Format {
+        match self.format {
+            OutputFormat::Table => Format::Table,
+            OutputFormat::Share => Format::Share(ShareReporter {
+                theme: self.theme,
+                high_contrast: self.high_contrast,
+                hard_mode,
+            }),
+            OutputFormat::Json => Format::Json,
+        }
+    }
+
+    fn is_json(&self) -> bool {
+        matches!(self.format, OutputFormat::Json)
+    }
+}
+
+#[derive(Args, Debug)]
+struct SolverArgs {
+    /// The opening guess, or the guess to explain (defaults to SALET without a --cache when it may be guessed, otherwise the solver's choice)
+    #[arg(short, long)]
+    guess: Option<String>,
+
+    /// The number of letters in each word
+    #[arg(long, default_value_t = DEFAULT_SIZE)]
+    size: usize,
+
+    /// A file of words that may be guessed, as a JSON array or one word per line
+    #[arg(long, env = "DODDLE_GUESSES")]
+    guesses: Option<PathBuf>,
+
+    /// A file of words that may be the answer, as a JSON array or one word per line
+    #[arg(long, env = "DODDLE_ANSWERS")]
+    answers: Option<PathBuf>,
+
+    /// Treat every word that may be guessed as a possible answer, for when the answers are unknown
+    #[arg(long, conflicts_with = "answers")]
+    any_answer: bool,
+
+    /// A file of word frequencies, as a JSON object or a word and its frequency per line, used to weight the answers
+    #[arg(long, env = "DODDLE_PRIORS")]
+    priors: Option<PathBuf>,
+
+    /// The algorithm used to choose each guess
     #[arg(short, long, default_value = "entropy", ignore_case = true)]
     solver: SolverType,
+
+    /// Play in hard mode, where every guess must use all of the hints revealed so far
+    #[arg(long)]
+    hard: bool,
+
+    /// Number of threads used to evaluate guesses (defaults to every available core)
+    #[arg(short, long, default_value_t = 0)]
+    threads: usize,
+
+    /// Precompute every score into this file and memory-map it on later runs
+    #[arg(short, long)]
+    matrix: Option<PathBuf>,
+
+    /// Save the solver's first and second guesses to this file and look them up on later runs
+    #[arg(long, env = "DODDLE_CACHE")]
+    cache: Option<PathBuf>,
+
+    /// What the optimal solver minimises
+    #[arg(long, default_value = "expected", ignore_case = true)]
+    objective: Objective,
+
+    /// Number of candidate guesses the optimal, lookahead and Absurdle solvers try at each position
+    #[arg(long, default_value_t = 10)]
+    top_k: usize,
+
+    /// The most guesses the optimal and Absurdle searches may spend on any answer
+    #[arg(long, default_value_t = 6)]
+    depth: usize,
+
+    /// Show the N best guesses and how each splits the remaining answers before every guess
+    #[arg(long, default_value_t = 0)]
+    top: usize,
+}
+
+impl SolverArgs {
+    fn dictionary(&self) -> Result<Dictionary, DictionaryError> {
+        let mut dictionary =
+            Dictionary::load(self.size, self.guesses.as_deref(), self.answers.as_deref())?;
+        if self.any_answer {
+            dictionary.potential_solns = dictionary.all_words.clone();
+        }
+
+        Ok(dictionary)
+    }
+
+    fn opening_guess(&self, dictionary: &Dictionary) -> Result<Option<Word>, WordError> {
+        // With a cache, the solver's own opening guess is only searched for once
+        match (&self.guess, &self.cache) {
+            (Some(guess), _) => dictionary.parse_guess(guess).map(Some),
+            (None, None) => {
+                let opening: Word = DEFAULT_OPENING.parse()?;
+                Ok(dictionary.guess_index(&opening).map(|_| opening))
+            }
+            (None, Some(_)) => Ok(None),
+        }
+    }
+
+    fn options(&self) -> Result<SolverOptions, DictionaryError> {
+        let matrix = match &self.matrix {
+            Some(path) => MatrixSource::File(path.clone()),
+            None => MatrixSource::None,
+        };
+
+        let search = match self.solver {
+            SolverType::Optimal => Some(SearchOptions {
+                objective: self.objective,
+                top_k: self.top_k,
+                max_depth: self.depth,
+            }),
+            _ => None,
+        };
+
+        let lookahead = match self.solver {
+            SolverType::Lookahead => Some(Lookahead { top_k: self.top_k }),
+            _ => None,
+        };
+
+        let priors = match &self.priors {
+            Some(path) => Some(Priors::load(path)?),
+            None => None,
+        };
+
+        Ok(SolverOptions {
+            hard_mode: self.hard,
+            num_threads: self.threads,
+            matrix,
+            search,
+            lookahead,
+            num_suggestions: self.top,
+            priors,
+            cache: self.cache.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use clap::CommandFactory;
+    use rstest::rstest;
+    use std::env;
+
+    #[test]
+    fn cli__for_every_command__has_no_conflicting_arguments() {
+        Cli::command().debug_assert();
+    }
+
+    #[rstest]
+    #[case(None, Some("SALET"))]
+    #[case(Some("TOWER\nROWER\nPOWER\n"), None)]
+    fn opening_guess__without_guess__uses_salet_when_allowed(
+        #[case] contents: Option<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        // Arrange
+        let path = env::temp_dir().join("doddle-opening-guesses.txt");
+        let mut args = vec!["metal-doddle", "solve", "--answer", "POWER"];
+        if let Some(contents) = contents {
+            fs::write(&path, contents).unwrap();
+            args.extend(["--guesses", path.to_str().unwrap()]);
+            args.extend(["--answers", path.to_str().unwrap()]);
+        }
+        let Command::Solve(sut) = Cli::parse_from(args).command else {
+            panic!("Expected the solve command");
+        };
+        let dictionary = sut.solver.dictionary().unwrap();
+
+        // Act
+        let actual = sut.solver.opening_guess(&dictionary).unwrap();
+
+        // Assert
+        let expected: Option<Word> = expected.map(|w| w.parse().unwrap());
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("SALET,TOWER", false, Ok(2))]
+    #[case("SALET,TOWER", true, Ok(2))]
+    #[case("SALET,POWER,TOWER", false, Ok(3))]
+    #[case("SALET,TOWER,POWER", false, Err("already found by guess 2"))]
+    #[case("SALET,POWER,TOWER", true, Err("POWER does not use every hint"))]
+    fn analyse_guesses__for_words__validates(
+        #[case] words: &str,
+        #[case] hard_mode: bool,
+        #[case] expected: Result<usize, &str>,
+    ) {
+        // Arrange
+        let mut args = vec![
+            "metal-doddle",
+            "analyse",
+            "--answer",
+            "TOWER",
+            "--words",
+            words,
+        ];
+        if hard_mode {
+            args.push("--hard");
+        }
+        let Command::Analyse(sut) = Cli::parse_from(args).command else {
+            panic!("Expected the analyse command");
+        };
+        let dictionary = sut.solver.dictionary().unwrap();
+        let soln = dictionary.parse_soln(&sut.answer).unwrap();
+
+        // Act
+        let actual = sut.guesses(&dictionary, &soln);
+
+        // Assert
+        match expected {
+            Ok(len) => assert_eq!(len, actual.unwrap().len()),
+            Err(message) => {
+                let error = actual.unwrap_err().to_string();
+                assert!(error.contains(message), "{error}");
+            }
+        }
+    }
 }
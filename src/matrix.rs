@@ -0,0 +1,286 @@
+use memmap2::Mmap;
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{self, Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::dictionary::Dictionary;
+use crate::files;
+use crate::scoring::{self, Score};
+
+const MAGIC: &[u8; 8] = b"DODDLESM";
+const HEADER_LEN: usize = 32;
+
+/// Where the solver should source its precomputed scores from.
+#[derive(Default, Debug, Clone)]
+pub enum MatrixSource {
+    /// Score each guess against each solution on demand.
+    #[default]
+    None,
+
+    /// Precompute every score up front and hold them in memory.
+    Memory,
+
+    /// Memory-map scores from a file, building and saving them first if needed.
+    File(PathBuf),
+}
+
+enum Storage {
+    Owned(Vec<u8>),
+    Mapped(Mmap),
+}
+
+/// The score of every guess in `all_words` against every word in
+/// `potential_solns`, indexed by their positions in the dictionary.
+pub struct ScoreMatrix {
+    num_guesses: usize,
+    num_solns: usize,
+
+    /// Bytes per score: one for words up to five letters, two little-endian bytes beyond that.
+    width: usize,
+    storage: Storage,
+}
+
+/// The scores of one guess against every solution.
+#[derive(Clone, Copy)]
+pub struct ScoreRow<'a> {
+    bytes: &'a [u8],
+    width: usize,
+}
+
+impl ScoreRow<'_> {
+    #[inline]
+    pub fn get(&self, soln: usize) -> Score {
+        match self.width {
+            1 => self.bytes[soln] as Score,
+            _ => Score::from_le_bytes([self.bytes[2 * soln], self.bytes[2 * soln + 1]]),
+        }
+    }
+}
+
+impl ScoreMatrix {
+    pub fn from_source(source: &MatrixSource, dictionary: &Dictionary) -> Option<ScoreMatrix> {
+        match source {
+            MatrixSource::None => None,
+            MatrixSource::Memory => Some(ScoreMatrix::build(dictionary)),
+            MatrixSource::File(path) => Some(ScoreMatrix::load_or_build(path, dictionary)),
+        }
+    }
+
+    pub fn build(dictionary: &Dictionary) -> ScoreMatrix {
+        let Dictionary {
+            all_words,
+            potential_solns,
+        } = dictionary;
+
+        let num_guesses = all_words.len();
+        let num_solns = potential_solns.len();
+        let width = ScoreMatrix::width(dictionary);
+        let mut data = vec![0_u8; num_guesses * num_solns * width];
+
+        if num_solns > 0 {
+            data.par_chunks_mut(num_solns * width)
+                .zip(all_words.par_iter())
+                .for_each(|(row, guess)| {
+                    for (cell, soln) in row.chunks_mut(width).zip(potential_solns) {
+                        let score = scoring::score(guess, soln).to_le_bytes();
+                        cell.copy_from_slice(&score[..width]);
+                    }
+                });
+        }
+
+        ScoreMatrix {
+            num_guesses,
+            num_solns,
+            width,
+            storage: Storage::Owned(data),
+        }
+    }
+
+    /// Memory-maps a previously saved matrix, rebuilding and saving it if the
+    /// file is missing or was built from a different dictionary.
+    pub fn load_or_build(path: &Path, dictionary: &Dictionary) -> ScoreMatrix {
+        if let Ok(matrix) = ScoreMatrix::load(path, dictionary) {
+            return matrix;
+        }
+
+        let matrix = ScoreMatrix::build(dictionary);
+        if let Err(error) = matrix.save(path, dictionary) {
+            eprintln!("Unable to save score matrix to {}: {error}", path.display());
+        }
+
+        matrix
+    }
+
+    pub fn load(path: &Path, dictionary: &Dictionary) -> io::Result<ScoreMatrix> {
+        let file = File::open(path)?;
+
+        // Safety: the file is only ever written whole by `save` and is validated below
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let num_guesses = dictionary.all_words.len();
+        let num_solns = dictionary.potential_solns.len();
+        let width = ScoreMatrix::width(dictionary);
+        let expected_header = ScoreMatrix::header(dictionary);
+
+        if mmap.len() != HEADER_LEN + num_guesses * num_solns * width
+            || mmap[..HEADER_LEN] != expected_header
+        {
+            let message = "Score matrix does not match the dictionary";
+            return Err(Error::new(ErrorKind::InvalidData, message));
+        }
+
+        Ok(ScoreMatrix {
+            num_guesses,
+            num_solns,
+            width,
+            storage: Storage::Mapped(mmap),
+        })
+    }
+
+    pub fn save(&self, path: &Path, dictionary: &Dictionary) -> io::Result<()> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.data().len());
+        bytes.extend_from_slice(&ScoreMatrix::header(dictionary));
+        bytes.extend_from_slice(self.data());
+
+        files::atomic_write(path, bytes)
+    }
+
+    #[inline]
+    pub fn row(&self, guess: usize) -> ScoreRow<'_> {
+        debug_assert!(guess < self.num_guesses);
+        let row_len = self.num_solns * self.width;
+        let start = guess * row_len;
+        ScoreRow {
+            bytes: &self.data()[start..start + row_len],
+            width: self.width,
+        }
+    }
+
+    fn width(dictionary: &Dictionary) -> usize {
+        if scoring::max_score(dictionary.size()) > u8::MAX as Score {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn data(&self) -> &[u8] {
+        match &self.storage {
+            Storage::Owned(data) => data,
+            Storage::Mapped(mmap) => &mmap[HEADER_LEN..],
+        }
+    }
+
+    fn header(dictionary: &Dictionary) -> [u8; HEADER_LEN] {
+        let num_guesses = dictionary.all_words.len() as u64;
+        let num_solns = dictionary.potential_solns.len() as u64;
+
+        let mut header = [0_u8; HEADER_LEN];
+        header[0..8].copy_from_slice(MAGIC);
+        header[8..16].copy_from_slice(&dictionary.fingerprint().to_le_bytes());
+        header[16..24].copy_from_slice(&num_guesses.to_le_bytes());
+        header[24..32].copy_from_slice(&num_solns.to_le_bytes());
+        header
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::fixtures::dictionary;
+    use rstest::rstest;
+    use std::{env, fs};
+
+    #[rstest]
+    fn build__for_dictionary__matches_scoring(dictionary: Dictionary) {
+        // Act
+        let actual = ScoreMatrix::build(&dictionary);
+
+        // Assert
+        assert_eq!(5, actual.num_guesses);
+        assert_eq!(5 * 3, actual.data().len());
+        for (i, guess) in dictionary.all_words.iter().enumerate() {
+            let row = actual.row(i);
+            for (j, soln) in dictionary.potential_solns.iter().enumerate() {
+                assert_eq!(scoring::score(guess, soln), row.get(j));
+            }
+        }
+    }
+
+    #[rstest]
+    fn save__then_load__roundtrips(dictionary: Dictionary) {
+        // Arrange
+        let path = env::temp_dir().join("doddle-matrix-roundtrip.bin");
+        let expected = ScoreMatrix::build(&dictionary);
+
+        // Act
+        expected.save(&path, &dictionary).unwrap();
+        let actual = ScoreMatrix::load(&path, &dictionary).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        assert_eq!(expected.num_guesses, actual.num_guesses);
+        assert_eq!(expected.num_solns, actual.num_solns);
+        assert_eq!(expected.data(), actual.data());
+    }
+
+    #[rstest]
+    fn load__for_another_dictionary__fails(dictionary: Dictionary) {
+        // Arrange
+        let path = env::temp_dir().join("doddle-matrix-mismatch.bin");
+        ScoreMatrix::build(&dictionary)
+            .save(&path, &dictionary)
+            .unwrap();
+
+        let mut other = dictionary;
+        other.potential_solns.swap(0, 1);
+
+        // Act
+        let actual = ScoreMatrix::load(&path, &other);
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        assert!(matches!(actual, Err(e) if e.kind() == ErrorKind::InvalidData));
+    }
+
+    #[rstest]
+    fn load_or_build__for_missing_file__builds_and_saves(dictionary: Dictionary) {
+        // Arrange
+        let path = env::temp_dir().join("doddle-matrix-missing.bin");
+        let _ = fs::remove_file(&path);
+
+        // Act
+        let actual = ScoreMatrix::load_or_build(&path, &dictionary);
+        let reloaded = ScoreMatrix::load(&path, &dictionary);
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        assert_eq!(5 * 3, actual.data().len());
+        assert!(reloaded.is_ok());
+    }
+
+    #[rstest]
+    fn from_source__for_long_words__stores_two_bytes_per_score() {
+        // Arrange
+        let dictionary = Dictionary {
+            all_words: vec!["SCALPEL".parse().unwrap(), "CALIPER".parse().unwrap()],
+            potential_solns: vec!["CALIPER".parse().unwrap(), "SCALPEL".parse().unwrap()],
+        };
+
+        // Act
+        let actual = ScoreMatrix::from_source(&MatrixSource::Memory, &dictionary).unwrap();
+
+        // Assert
+        // A win at seven letters scores 3^7 - 1, which does not fit in a byte
+        assert_eq!(2 * 2 * 2, actual.data().len());
+        for (i, guess) in dictionary.all_words.iter().enumerate() {
+            for (j, soln) in dictionary.potential_solns.iter().enumerate() {
+                assert_eq!(scoring::score(guess, soln), actual.row(i).get(j));
+            }
+        }
+        assert_eq!(scoring::max_score(7), actual.row(1).get(0));
+    }
+}
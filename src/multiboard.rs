@@ -0,0 +1,269 @@
+use std::io::{self, BufRead};
+use std::time::Instant;
+
+use crate::boards::Scoreboard;
+use crate::guess::Algorithm;
+use crate::reporting::Reporter;
+use crate::scoring::{self, Score};
+use crate::solver::{read_score, Solve, Solver, MAX_ITERS};
+use crate::word::Word;
+
+pub trait SolveBoards {
+    fn solve(&self, solns: &[Word], opening_guess: Option<Word>) -> Option<Vec<Scoreboard>>;
+    fn solve_interactive(
+        &self,
+        num_boards: usize,
+        opening_guess: Option<Word>,
+    ) -> Option<Vec<Scoreboard>>;
+}
+
+/// Plays Dordle, Quordle, Octordle and the like, where every guess is scored
+/// against several boards at once and the game ends when every board is solved.
+pub struct MultiSolver<T> {
+    solver: Solver<T>,
+    reporter: Box<dyn Reporter>,
+}
+
+impl<T: Algorithm> MultiSolver<T> {
+    pub fn new(solver: Solver<T>, reporter: Box<dyn Reporter>) -> MultiSolver<T> {
+        MultiSolver { solver, reporter }
+    }
+
+    /// Solves for known `solns`, one per board.
+    pub fn run(&self, solns: &[Word], opening_guess: Option<Word>) -> Option<Vec<Scoreboard>> {
+        let now = Instant::now();
+
+        let scoreboards = self.play(solns.len(), Some(solns), opening_guess, |board, guess| {
+            Some(scoring::score(guess, &solns[board]))
+        })?;
+
+        for scoreboard in &scoreboards {
+            self.reporter.print(scoreboard);
+        }
+        self.reporter
+            .report_success(scoreboards.last()?, now.elapsed());
+        Some(scoreboards)
+    }
+
+    pub fn run_interactive(
+        &self,
+        num_boards: usize,
+        opening_guess: Option<Word>,
+        input: &mut impl BufRead,
+    ) -> Option<Vec<Scoreboard>> {
+        eprintln!("Begin interactive solve of {num_boards} boards...");
+        eprintln!(
+            "Enter each score as a ternary string (e.g. 01020) or as colours (e.g. BYBGB).\n"
+        );
+
+        let scoreboards = self.play(num_boards, None, opening_guess, |board, guess| {
+            let prompt = format!("Guess {guess} and enter the score on board {}: ", board + 1);
+            read_score(&prompt, guess.len(), input)
+        })?;
+
+        eprintln!();
+        for scoreboard in &scoreboards {
+            self.reporter.print(scoreboard);
+        }
+        Some(scoreboards)
+    }
+
+    fn play(
+        &self,
+        num_boards: usize,
+        solns: Option<&[Word]>,
+        opening_guess: Option<Word>,
+        mut observe: impl FnMut(usize, &Word) -> Option<Score>,
+    ) -> Option<Vec<Scoreboard>> {
+        let num_solns = self.solver.dictionary().potential_solns.len();
+        let mut boards: Vec<Vec<usize>> = vec![(0..num_solns).collect(); num_boards];
+        let mut scoreboards = vec![Scoreboard::default(); num_boards];
+        let mut guess = opening_guess.unwrap_or_else(|| Solve::opening_guess(&self.solver));
+
+        for _i in 0..MAX_ITERS {
+            for (i, (board, scoreboard)) in boards.iter_mut().zip(&mut scoreboards).enumerate() {
+                if scoreboard.is_solved() {
+                    continue;
+                }
+
+                let observed_score = observe(i, &guess)?;
+                *board = self.solver.trim_solns(&guess, observed_score, board);
+                let soln = solns.map(|solns| solns[i].clone());
+                scoreboard.add_row(soln, guess.clone(), observed_score, board.len());
+
+                if board.is_empty() {
+                    self.reporter.report_failure(scoreboard);
+                    return None;
+                }
+            }
+
+            if scoreboards.iter().all(Scoreboard::is_solved) {
+                return Some(scoreboards);
+            }
+
+            // In hard mode, the boards can demand hints that no single guess can use
+            let Some(next_guess) = self.next_guess(&boards, &scoreboards) else {
+                break;
+            };
+            guess = next_guess;
+        }
+
+        for scoreboard in scoreboards.iter().filter(|s| !s.is_solved()) {
+            self.reporter.report_failure(scoreboard);
+        }
+        None
+    }
+
+    /// The best guess for the unsolved boards, which in hard mode must use every
+    /// hint revealed on each of them, or `None` if no such guess makes progress.
+    fn next_guess(&self, boards: &[Vec<usize>], scoreboards: &[Scoreboard]) -> Option<Word> {
+        let dictionary = self.solver.dictionary();
+        let (unsolved, unsolved_scoreboards): (Vec<&[usize]>, Vec<&Scoreboard>) = boards
+            .iter()
+            .zip(scoreboards)
+            .filter(|(_, scoreboard)| !scoreboard.is_solved())
+            .map(|(board, scoreboard)| (board.as_slice(), scoreboard))
+            .unzip();
+
+        let mut guesses: Vec<usize> = (0..dictionary.all_words.len()).collect();
+        for scoreboard in unsolved_scoreboards {
+            let permitted = self.solver.candidate_guesses(scoreboard);
+            guesses.retain(|guess| permitted.binary_search(guess).is_ok());
+        }
+
+        // Once a board is down to one answer, playing it is a free win
+        let free_win = unsolved
+            .iter()
+            .filter(|board| board.len() == 1)
+            .map(|board| &dictionary.potential_solns[board[0]])
+            .find(|soln| {
+                let index = dictionary.guess_index(soln);
+                index.is_some_and(|index| guesses.binary_search(&index).is_ok())
+            });
+        if let Some(soln) = free_win {
+            return Some(soln.clone());
+        }
+
+        if guesses.is_empty() {
+            return None;
+        }
+
+        // A guess that cannot narrow down or solve any board would only be played again
+        let guess: Word = self.solver.best_combined_guess(&guesses, &unsolved).into();
+        let max_score = scoring::max_score(guess.len());
+        let makes_progress = unsolved.iter().any(|board| {
+            let partitions = self.solver.partition(&guess, board);
+            partitions.len() > 1 || partitions.contains_key(&max_score)
+        });
+
+        makes_progress.then_some(guess)
+    }
+}
+
+impl<T: Algorithm> SolveBoards for MultiSolver<T> {
+    fn solve(&self, solns: &[Word], opening_guess: Option<Word>) -> Option<Vec<Scoreboard>> {
+        self.run(solns, opening_guess)
+    }
+
+    fn solve_interactive(
+        &self,
+        num_boards: usize,
+        opening_guess: Option<Word>,
+    ) -> Option<Vec<Scoreboard>> {
+        let stdin = io::stdin();
+        self.run_interactive(num_boards, opening_guess, &mut stdin.lock())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::dictionary::Dictionary;
+    use crate::fixtures::dictionary;
+    use crate::guess::{EntropyAlgorithm, MinimaxAlgorithm};
+    use crate::reporting::NullReporter;
+    use crate::solver::SolverOptions;
+    use rstest::rstest;
+    use std::io::Cursor;
+
+    #[rstest]
+    fn run__for_two_boards__solves_both_with_shared_guesses(dictionary: Dictionary) {
+        // Arrange
+        let sut = MultiSolver::new(
+            Solver::new(
+                EntropyAlgorithm,
+                Box::new(NullReporter),
+                dictionary,
+                SolverOptions::default(),
+            ),
+            Box::new(NullReporter),
+        );
+        let solns: Vec<Word> = vec!["POWER".parse().unwrap(), "TOWER".parse().unwrap()];
+
+        // Act
+        let actual = sut.run(&solns, Some("SALET".parse().unwrap()));
+
+        // Assert
+        let scoreboards = actual.unwrap();
+        assert_eq!(2, scoreboards.len());
+        assert!(scoreboards.iter().all(Scoreboard::is_solved));
+
+        let longest = scoreboards.iter().max_by_key(|s| s.len()).unwrap();
+        for (scoreboard, soln) in scoreboards.iter().zip(&solns) {
+            assert_eq!(Some(soln), scoreboard.rows.last().unwrap().soln.as_ref());
+            for (row, shared) in scoreboard.rows.iter().zip(&longest.rows) {
+                assert_eq!(shared.guess, row.guess);
+            }
+        }
+    }
+
+    #[rstest]
+    fn run__for_same_answer_twice__solves_together(dictionary: Dictionary) {
+        // Arrange
+        let sut = MultiSolver::new(
+            Solver::new(
+                MinimaxAlgorithm,
+                Box::new(NullReporter),
+                dictionary,
+                SolverOptions::default(),
+            ),
+            Box::new(NullReporter),
+        );
+        let solns: Vec<Word> = vec!["ROWER".parse().unwrap(), "ROWER".parse().unwrap()];
+
+        // Act
+        let actual = sut.run(&solns, Some("ROWER".parse().unwrap()));
+
+        // Assert
+        let scoreboards = actual.unwrap();
+        assert!(scoreboards.iter().all(|s| s.len() == 1 && s.is_solved()));
+    }
+
+    #[rstest]
+    fn run_interactive__with_scores__stops_asking_for_solved_boards(dictionary: Dictionary) {
+        // Arrange
+        let sut = MultiSolver::new(
+            Solver::new(
+                MinimaxAlgorithm,
+                Box::new(NullReporter),
+                dictionary,
+                SolverOptions::default(),
+            ),
+            Box::new(NullReporter),
+        );
+
+        // TOWER solves the first board, leaving ROWER or POWER on the second
+        let mut input = Cursor::new("22222\n02222\nGGGGG\n");
+
+        // Act
+        let actual = sut.run_interactive(2, Some("TOWER".parse().unwrap()), &mut input);
+
+        // Assert
+        let scoreboards = actual.unwrap();
+        assert_eq!(1, scoreboards[0].len());
+        assert_eq!(2, scoreboards[1].len());
+        assert!(scoreboards[1].rows.iter().all(|r| r.soln.is_none()));
+    }
+}
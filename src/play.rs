@@ -0,0 +1,246 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::BufRead;
+
+use crate::boards::{Scoreboard, MAX_GUESSES};
+use crate::dictionary::Dictionary;
+use crate::reporting::ConsoleReporter;
+use crate::scoring::{self, Score};
+use crate::word::{Word, WordError};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GuessError {
+    Invalid(WordError),
+    BreaksHardMode(Word),
+    GameOver,
+}
+
+impl Display for GuessError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            GuessError::Invalid(error) => write!(f, "{error}"),
+            GuessError::BreaksHardMode(guess) => write!(
+                f,
+                "{guess} does not use every hint revealed so far, as hard mode requires"
+            ),
+            GuessError::GameOver => write!(f, "The game is over"),
+        }
+    }
+}
+
+/// A game for a person to play, where the answer is hidden until the end.
+pub struct Game<'a> {
+    dictionary: &'a Dictionary,
+    soln: Word,
+    hard_mode: bool,
+    potential_solns: Vec<Word>,
+    scoreboard: Scoreboard,
+}
+
+impl<'a> Game<'a> {
+    pub fn new(dictionary: &'a Dictionary, soln: Word, hard_mode: bool) -> Game<'a> {
+        Game {
+            dictionary,
+            soln,
+            hard_mode,
+            potential_solns: dictionary.potential_solns.clone(),
+            scoreboard: Scoreboard::default(),
+        }
+    }
+
+    /// Hides a random answer. The same `seed` always hides the same answer.
+    pub fn random(dictionary: &'a Dictionary, seed: Option<u64>, hard_mode: bool) -> Game<'a> {
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let soln = dictionary
+            .potential_solns
+            .choose(&mut rng)
+            .expect("The dictionary has no answers")
+            .clone();
+
+        Game::new(dictionary, soln, hard_mode)
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.scoreboard.is_solved() || self.scoreboard.len() >= MAX_GUESSES
+    }
+
+    /// Scores a guess, which must be a word in the dictionary that, in hard mode,
+    /// uses every hint revealed so far.
+    pub fn guess(&mut self, value: &str) -> Result<Score, GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+
+        let guess = self
+            .dictionary
+            .parse_guess(value)
+            .map_err(GuessError::Invalid)?;
+        if self.hard_mode && !self.scoreboard.permits(&guess) {
+            return Err(GuessError::BreaksHardMode(guess));
+        }
+
+        let score = scoring::score(&guess, &self.soln);
+        self.potential_solns
+            .retain(|soln| scoring::score(&guess, soln) == score);
+
+        // The answer stays hidden on the board until the game is over
+        let num_left = self.potential_solns.len();
+        self.scoreboard.add_row(None, guess, score, num_left);
+        Ok(score)
+    }
+
+    /// Reads guesses from `input` until the game is over, or returns `None` at the end of the input.
+    /// The board is drawn on stderr along with the prompts, leaving stdout for the result.
+    pub fn run(&mut self, input: &mut impl BufRead) -> Option<Scoreboard> {
+        let mode = if self.hard_mode { " in hard mode" } else { "" };
+        eprintln!(
+            "Guess the {} letter word{mode} in {MAX_GUESSES} tries...\n",
+            self.soln.len()
+        );
+
+        while !self.is_over() {
+            eprint!(
+                "Enter guess {} of {MAX_GUESSES}: ",
+                self.scoreboard.len() + 1
+            );
+
+            let mut line = String::new();
+            if input.read_line(&mut line).ok()? == 0 {
+                eprintln!("\nThe answer was {}.", self.soln);
+                return None;
+            }
+
+            match self.guess(line.trim()) {
+                Ok(_) => eprintln!("{}", ConsoleReporter::report_tail(&self.scoreboard)),
+                Err(error) => eprintln!("{error}"),
+            }
+        }
+
+        if self.scoreboard.is_solved() {
+            eprintln!("\nSolved in {}/{MAX_GUESSES}.", self.scoreboard.len());
+        } else {
+            eprintln!("\nOut of guesses. The answer was {}.", self.soln);
+        }
+
+        Some(self.scoreboard.clone())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::fixtures::dictionary;
+    use rstest::rstest;
+    use std::io::Cursor;
+
+    #[rstest]
+    fn random__for_same_seed__hides_same_answer(dictionary: Dictionary) {
+        // Act
+        let first = Game::random(&dictionary, Some(42), false);
+        let second = Game::random(&dictionary, Some(42), false);
+
+        // Assert
+        assert_eq!(&first.soln, &second.soln);
+        assert!(dictionary.potential_solns.contains(&first.soln));
+    }
+
+    #[rstest]
+    #[case("SOARE", Ok(scoring::str_to_score("02011")))]
+    #[case("LOWER", Err(GuessError::Invalid(WordError::NotInDictionary("LOWER".into()))))]
+    #[case("TOWERS", Err(GuessError::Invalid(WordError::WrongLength { word: "TOWERS".into(), expected: 5 })))]
+    fn guess__for_input__scores_valid_words(
+        dictionary: Dictionary,
+        #[case] value: &str,
+        #[case] expected: Result<Score, GuessError>,
+    ) {
+        // Arrange
+        let mut sut = Game::new(&dictionary, "TOWER".parse().unwrap(), false);
+
+        // Act
+        let actual = sut.guess(value);
+
+        // Assert
+        assert_eq!(expected, actual);
+        assert_eq!(expected.is_ok() as usize, sut.scoreboard.len());
+    }
+
+    #[rstest]
+    fn guess__in_hard_mode__enforces_hints(dictionary: Dictionary) {
+        // Arrange
+        let mut sut = Game::new(&dictionary, "POWER".parse().unwrap(), true);
+        sut.guess("TOWER").unwrap();
+
+        // Act
+        let actual = sut.guess("SALET");
+
+        // Assert
+        assert_eq!(
+            Err(GuessError::BreaksHardMode("SALET".parse().unwrap())),
+            actual
+        );
+        assert!(sut.guess("ROWER").is_ok());
+    }
+
+    #[rstest]
+    fn guess__after_six_guesses__is_game_over(dictionary: Dictionary) {
+        // Arrange
+        let mut sut = Game::new(&dictionary, "POWER".parse().unwrap(), false);
+        for _ in 0..MAX_GUESSES {
+            sut.guess("SALET").unwrap();
+        }
+
+        // Act
+        let actual = sut.guess("POWER");
+
+        // Assert
+        assert_eq!(Err(GuessError::GameOver), actual);
+        assert!(sut.is_over());
+        assert!(!sut.scoreboard.is_solved());
+    }
+
+    #[rstest]
+    fn run__with_guesses__plays_until_solved(dictionary: Dictionary) {
+        // Arrange
+        let mut sut = Game::new(&dictionary, "POWER".parse().unwrap(), false);
+        let mut input = Cursor::new("salet\nlower\ntower\npower\n");
+
+        // Act
+        let actual = sut.run(&mut input);
+
+        // Assert
+        let scoreboard = actual.unwrap();
+        let guesses: Vec<String> = scoreboard.rows.iter().map(|r| r.guess.value()).collect();
+        assert_eq!(vec!["SALET", "TOWER", "POWER"], guesses);
+        assert!(scoreboard.is_solved());
+        assert!(scoreboard.rows.iter().all(|r| r.soln.is_none()));
+        assert_eq!(
+            vec![2, 2, 1],
+            scoreboard
+                .rows
+                .iter()
+                .map(|r| r.num_left)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    fn run__at_end_of_input__returns_none(dictionary: Dictionary) {
+        // Arrange
+        let mut sut = Game::new(&dictionary, "POWER".parse().unwrap(), false);
+        let mut input = Cursor::new("salet\n");
+
+        // Act
+        let actual = sut.run(&mut input);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+}
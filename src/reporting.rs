@@ -1,13 +1,35 @@
 use colored::Colorize;
 use itertools::Itertools;
+use serde_json::{json, Value};
 
 use crate::boards::{Scoreboard, ScoreboardRow};
-use crate::scoring::{score_to_str, MAX_SCORE};
+use crate::guess::GuessMetrics;
+use crate::scoring::score_to_str;
+use crate::share::ShareReporter;
+use crate::word::Word;
+use std::time::Duration;
+
+/// How each game is written out.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Format {
+    /// A table of guesses and scores, coloured for the terminal.
+    #[default]
+    Table,
+
+    /// The grid of emoji squares Wordle shares.
+    Share(ShareReporter),
+
+    /// A line of JSON per game, for other programs to read.
+    Json,
+}
 
-pub trait Reporter {
+pub trait Reporter: Sync {
     fn print(&self, scoreboard: &Scoreboard);
     fn print_tail(&self, scoreboard: &Scoreboard);
+    fn report_start(&self, soln: &Word);
+    fn report_success(&self, scoreboard: &Scoreboard, elapsed: Duration);
     fn report_failure(&self, scoreboard: &Scoreboard);
+    fn report_suggestions(&self, suggestions: &[GuessMetrics]);
 }
 
 pub struct NullReporter;
@@ -15,19 +37,45 @@ pub struct NullReporter;
 impl Reporter for NullReporter {
     fn print(&self, _scoreboard: &Scoreboard) {}
     fn print_tail(&self, _scoreboard: &Scoreboard) {}
+    fn report_start(&self, _soln: &Word) {}
+    fn report_success(&self, _scoreboard: &Scoreboard, _elapsed: Duration) {}
     fn report_failure(&self, _scoreboard: &Scoreboard) {}
+    fn report_suggestions(&self, _suggestions: &[GuessMetrics]) {}
 }
 
 pub struct ConsoleReporter;
 
 impl ConsoleReporter {
-    fn build_header_str() -> String {
-        "| # | Soln. | Guess | Score | Poss. |\n|---|-------|-------|-------|-------|".to_string()
+    /// Why the game was lost: either no answer fits the scores, or it ran out of guesses.
+    fn failure_str(scoreboard: &Scoreboard) -> String {
+        match scoreboard.rows.last() {
+            Some(row) if row.num_left == 0 => {
+                "No words in the dictionary are consistent with the scores observed.".to_string()
+            }
+            _ => format!("Failed to converge after {} iterations.", scoreboard.len()),
+        }
+    }
+
+    fn build_header_str(size: usize) -> String {
+        let width = ConsoleReporter::column_width(size);
+        let line = "-".repeat(width + 2);
+        let header = format!(
+            "| # | {:<width$} | {:<width$} | {:<width$} | Poss. |",
+            "Soln.", "Guess", "Score"
+        );
+        let underline = format!("|---|{line}|{line}|{line}|-------|");
+        [header, underline].join("\n")
+    }
+
+    pub fn column_width(size: usize) -> usize {
+        // Leave room for the column titles when words are short
+        size.max(5)
     }
 
     fn report(scoreboard: &Scoreboard) -> String {
         let mut rows = Vec::new();
-        let header = ConsoleReporter::build_header_str();
+        let size = scoreboard.rows.first().map_or(5, |row| row.guess.len());
+        let header = ConsoleReporter::build_header_str(size);
         rows.push(header);
 
         for row in &scoreboard.rows {
@@ -38,12 +86,12 @@ impl ConsoleReporter {
         rows.iter().join("\n")
     }
 
-    fn report_tail(scoreboard: &Scoreboard) -> String {
+    pub fn report_tail(scoreboard: &Scoreboard) -> String {
         let last_row = scoreboard.rows.last().unwrap();
         let row_str = ConsoleReporter::build_row_str(last_row);
 
         if scoreboard.len() <= 1 {
-            let header = ConsoleReporter::build_header_str();
+            let header = ConsoleReporter::build_header_str(last_row.guess.len());
             [header, row_str].join("\n")
         } else {
             row_str
@@ -52,13 +100,19 @@ impl ConsoleReporter {
 
     fn build_row_str(row: &ScoreboardRow) -> String {
         let n = row.n;
-        let soln = &row.soln;
         let guess = &row.guess;
+        let size = guess.len();
+        let width = ConsoleReporter::column_width(size);
+        let padding = " ".repeat(width - size);
+        let soln = match &row.soln {
+            Some(soln) => soln.value(),
+            None => "?".repeat(size),
+        };
         let score = row.score;
-        let ternary = score_to_str(score);
+        let ternary = score_to_str(score, size);
         let num_left = row.num_left;
 
-        let remaining = if score == MAX_SCORE as u8 {
+        let remaining = if row.is_solved() {
             " ".into()
         } else {
             format!("{num_left}")
@@ -67,11 +121,43 @@ impl ConsoleReporter {
         let guess = ConsoleReporter::prettify(&guess.value(), &ternary);
         let ternary = ConsoleReporter::prettify(&ternary, &ternary);
 
-        format!("| {n} | {soln} | {guess} | {ternary} | {remaining: >5} |")
+        format!(
+            "| {n} | {soln:<width$} | {guess}{padding} | {ternary}{padding} | {remaining: >5} |"
+        )
     }
 
-    fn prettify(string: &str, mask: &str) -> String {
-        let mut characters = Vec::new();
+    fn report_suggestions_str(suggestions: &[GuessMetrics]) -> String {
+        let width = suggestions
+            .first()
+            .map_or(5, |m| ConsoleReporter::column_width(m.word.len()));
+        let line = "-".repeat(width + 2);
+
+        let mut rows = vec![
+            format!(
+                "| {:<width$} |  Bits | Largest | Buckets | Expected | Soln. |",
+                "Guess"
+            ),
+            format!("|{line}|-------|---------|---------|----------|-------|"),
+        ];
+
+        for m in suggestions {
+            let soln = if m.is_potential_soln { "yes" } else { "" };
+            rows.push(format!(
+                "| {:<width$} | {:>5.3} | {:>7} | {:>7} | {:>8.2} | {soln:>5} |",
+                m.word.value(),
+                m.entropy,
+                m.largest_bucket,
+                m.num_buckets,
+                m.expected_size
+            ));
+        }
+
+        rows.join("\n")
+    }
+
+    /// Colours each character of `string` by the matching digit of a ternary score.
+    pub fn prettify(string: &str, mask: &str) -> String {
+        let mut characters = String::new();
         for (c, m) in string.chars().zip(mask.chars()) {
             let colored = match m {
                 '0' => c.to_string().normal(),
@@ -79,13 +165,10 @@ impl ConsoleReporter {
                 '2' => c.to_string().green(),
                 _ => panic!("Unexpected character in ternary score: '{m}'"),
             };
-            characters.push(colored);
+            characters.push_str(&colored.to_string());
         }
 
-        format!(
-            "{}{}{}{}{}",
-            characters[0], characters[1], characters[2], characters[3], characters[4]
-        )
+        characters
     }
 }
 
@@ -100,9 +183,57 @@ impl Reporter for ConsoleReporter {
         println!("{result}");
     }
 
+    fn report_start(&self, soln: &Word) {
+        println!("Begin solve for solution {soln}...\n");
+    }
+
+    fn report_success(&self, _scoreboard: &Scoreboard, elapsed: Duration) {
+        println!("Elapsed: {:.2?}\n", elapsed);
+    }
+
     fn report_failure(&self, scoreboard: &Scoreboard) {
-        let num = scoreboard.len();
-        println!("Failed to converge after {num} iterations.");
+        println!("{}", ConsoleReporter::failure_str(scoreboard));
+    }
+
+    fn report_suggestions(&self, suggestions: &[GuessMetrics]) {
+        let result = ConsoleReporter::report_suggestions_str(suggestions);
+        println!("\n{result}\n");
+    }
+}
+
+/// Writes each game as a single line of JSON, as documented in the README.
+pub struct JsonReporter;
+
+impl JsonReporter {
+    pub fn report(scoreboard: &Scoreboard, elapsed: Option<Duration>) -> Value {
+        json!({
+            "solved": scoreboard.is_solved(),
+            "num_guesses": scoreboard.len(),
+            "elapsed_ms": elapsed.map(|elapsed| elapsed.as_secs_f64() * 1000.0),
+            "rows": scoreboard.rows,
+        })
+    }
+}
+
+impl Reporter for JsonReporter {
+    fn print(&self, scoreboard: &Scoreboard) {
+        println!("{}", JsonReporter::report(scoreboard, None));
+    }
+
+    // Only whole games are written, so that every line is a complete document
+    fn print_tail(&self, _scoreboard: &Scoreboard) {}
+    fn report_start(&self, _soln: &Word) {}
+
+    fn report_success(&self, scoreboard: &Scoreboard, elapsed: Duration) {
+        println!("{}", JsonReporter::report(scoreboard, Some(elapsed)));
+    }
+
+    fn report_failure(&self, scoreboard: &Scoreboard) {
+        println!("{}", JsonReporter::report(scoreboard, None));
+    }
+
+    fn report_suggestions(&self, suggestions: &[GuessMetrics]) {
+        println!("{}", json!({ "suggestions": suggestions }));
     }
 }
 
@@ -111,6 +242,7 @@ impl Reporter for ConsoleReporter {
 mod tests {
 
     use super::*;
+    use crate::scoring::max_score;
     use rstest::{fixture, rstest};
 
     #[rstest]
@@ -122,6 +254,27 @@ mod tests {
         sut.report_failure(&scoreboard);
     }
 
+    #[rstest]
+    #[case(3, "Failed to converge after 3 iterations.")]
+    #[case(
+        0,
+        "No words in the dictionary are consistent with the scores observed."
+    )]
+    fn failure_str__for_last_row__explains(
+        mut scoreboard: Scoreboard,
+        #[case] num_left: usize,
+        #[case] expected: &str,
+    ) {
+        // Arrange
+        scoreboard.rows.last_mut().unwrap().num_left = num_left;
+
+        // Act
+        let actual = ConsoleReporter::failure_str(&scoreboard);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
     #[rstest]
     fn print_tail__with_scoreboard__prints(scoreboard: Scoreboard) {
         // Act
@@ -140,6 +293,75 @@ mod tests {
         assert!(actual.len() > 10);
     }
 
+    #[rstest]
+    fn report_suggestions_str__for_metrics__aligns_columns() {
+        // Arrange
+        let suggestions = vec![GuessMetrics {
+            word: "SALET".parse().unwrap(),
+            entropy: 5.8779,
+            largest_bucket: 221,
+            num_buckets: 148,
+            expected_size: 71.27,
+            is_potential_soln: false,
+        }];
+
+        // Act
+        let actual = ConsoleReporter::report_suggestions_str(&suggestions);
+
+        // Assert
+        let lines: Vec<&str> = actual.lines().collect();
+        assert_eq!(
+            "| SALET | 5.878 |     221 |     148 |    71.27 |       |",
+            lines[2]
+        );
+        assert!(lines.iter().all(|l| l.len() == lines[0].len()));
+    }
+
+    #[rstest]
+    fn build_header_str__for_five_letters__is_unchanged() {
+        // Arrange
+        let expected =
+            "| # | Soln. | Guess | Score | Poss. |\n|---|-------|-------|-------|-------|";
+
+        // Act
+        let actual = ConsoleReporter::build_header_str(5);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    fn build_row_str__for_seven_letters__aligns_with_header() {
+        // Arrange
+        colored::control::set_override(false);
+        let mut scoreboard = Scoreboard::default();
+        let score = crate::scoring::str_to_score("0111220");
+        scoreboard.add_row(None, "SCALPEL".parse().unwrap(), score, 12);
+
+        // Act
+        let actual = ConsoleReporter::report(&scoreboard);
+
+        // Assert
+        let lines: Vec<&str> = actual.lines().collect();
+        assert_eq!("| 1 | ??????? | SCALPEL | 0111220 |    12 |", lines[2]);
+        assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+    }
+
+    #[rstest]
+    fn json_report__for_scoreboard__includes_rows_and_timing(scoreboard: Scoreboard) {
+        // Act
+        let actual = JsonReporter::report(&scoreboard, Some(Duration::from_millis(12)));
+
+        // Assert
+        assert_eq!(true, actual["solved"]);
+        assert_eq!(3, actual["num_guesses"]);
+        assert_eq!(12.0, actual["elapsed_ms"]);
+        assert_eq!("SOARE", actual["rows"][0]["guess"]);
+        assert_eq!("01120", actual["rows"][0]["score"]);
+        assert_eq!(123, actual["rows"][0]["num_left"]);
+        assert_eq!("22222", actual["rows"][2]["score"]);
+    }
+
     #[fixture]
     fn scoreboard() -> Scoreboard {
         // Arrange
@@ -147,25 +369,25 @@ mod tests {
 
         let row1 = ScoreboardRow {
             n: 1,
-            soln: "SNAKE".into(),
-            guess: "SOARE".into(),
+            soln: Some("SNAKE".parse().unwrap()),
+            guess: "SOARE".parse().unwrap(),
             score: 42,
             num_left: 123,
         };
 
         let row2 = ScoreboardRow {
             n: 1,
-            soln: "SNAKE".into(),
-            guess: "CLINT".into(),
+            soln: Some("SNAKE".parse().unwrap()),
+            guess: "CLINT".parse().unwrap(),
             score: 142,
             num_left: 3,
         };
 
         let row3 = ScoreboardRow {
             n: 1,
-            soln: "SNAKE".into(),
-            guess: "SNAKE".into(),
-            score: MAX_SCORE as u8,
+            soln: Some("SNAKE".parse().unwrap()),
+            guess: "SNAKE".parse().unwrap(),
+            score: max_score(5),
             num_left: 1,
         };
 
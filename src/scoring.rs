@@ -1,34 +1,100 @@
-use crate::word::{Word, SIZE};
+use crate::word::{Word, MAX_SIZE};
 use radix_fmt::radix_3;
 
+/// A score packed as a ternary number, one digit per letter. A `u16` has room
+/// for words of up to ten letters.
+pub type Score = u16;
+
 const NUM_INDICATORS: usize = 3;
-pub const MAX_SCORE: usize = NUM_INDICATORS.pow(SIZE as u32) - 1;
+const POWERS: [Score; MAX_SIZE] = [19683, 6561, 2187, 729, 243, 81, 27, 9, 3, 1];
 
 pub const GREEN: u8 = 2;
 pub const AMBER: u8 = 1;
 pub const GREY: u8 = 0;
 
-pub fn score_to_str(score: u8) -> String {
-    format!("{:0>5}", radix_3(score).to_string())
+/// The number of distinct scores a word of `size` letters can receive.
+pub fn num_scores(size: usize) -> usize {
+    NUM_INDICATORS.pow(size as u32)
+}
+
+/// The score of a correct guess, where every letter is green.
+pub fn max_score(size: usize) -> Score {
+    (num_scores(size) - 1) as Score
+}
+
+pub fn score_to_str(score: Score, size: usize) -> String {
+    format!("{:0>size$}", radix_3(score).to_string())
+}
+
+pub fn str_to_score(ternary: &str) -> Score {
+    usize::from_str_radix(ternary, 3).unwrap() as Score
+}
+
+/// Parses a score entered by a human, either as a ternary string such as
+/// `01020` or as colours such as `BYBGB` (Grey/Black, Yellow, Green).
+pub fn parse_score(input: &str, size: usize) -> Option<Score> {
+    let input = input.trim();
+    if input.chars().count() != size {
+        return None;
+    }
+
+    let mut ternary = String::with_capacity(size);
+    for c in input.chars() {
+        let digit = match c.to_ascii_uppercase() {
+            '0' | 'B' | 'W' | 'X' | '.' => '0',
+            '1' | 'Y' | 'A' => '1',
+            '2' | 'G' => '2',
+            _ => return None,
+        };
+        ternary.push(digit);
+    }
+
+    Some(str_to_score(&ternary))
 }
 
-#[allow(dead_code)]
-pub fn str_to_score(ternary: &str) -> u8 {
-    usize::from_str_radix(ternary, 3).unwrap() as u8
+/// Checks whether `guess` honours the hints revealed by an earlier guess under
+/// hard mode rules: every green is reused in place and every amber is included.
+pub fn satisfies_hard_mode(guess: &Word, prev_guess: &Word, prev_score: Score) -> bool {
+    let mut required = [0_u8; 26];
+    let mut available = [0_u8; 26];
+    let mut remainder = prev_score;
+
+    let guess = guess.letters();
+    let prev_guess = prev_guess.letters();
+
+    for i in (0..prev_guess.len()).rev() {
+        let indicator = (remainder % NUM_INDICATORS as Score) as u8;
+        remainder /= NUM_INDICATORS as Score;
+
+        let letter = prev_guess[i];
+        if indicator == GREEN && guess[i] != letter {
+            return false;
+        }
+
+        if indicator != GREY {
+            required[letter as usize] += 1;
+        }
+    }
+
+    for &letter in guess {
+        available[letter as usize] += 1;
+    }
+
+    required.iter().zip(available).all(|(&r, a)| a >= r)
 }
 
 #[inline]
-pub fn score(guess: &Word, soln: &Word) -> u8 {
-    let mut value: u8 = GREY;
+pub fn score(guess: &Word, soln: &Word) -> Score {
+    let mut value: Score = GREY as Score;
 
-    let guess = &guess.vector;
-    let soln = &soln.vector;
-    let powers: [u8; SIZE] = [81, 27, 9, 3, 1];
+    let guess = guess.letters();
+    let soln = soln.letters();
+    let powers = &POWERS[MAX_SIZE - guess.len()..];
     let mut misplaced = [0_u8; 26];
 
     for (i, (&g, &s)) in guess.iter().zip(soln).enumerate() {
         if g == s {
-            value += GREEN * powers[i];
+            value += GREEN as Score * powers[i];
         } else {
             misplaced[s as usize] += 1;
         }
@@ -38,7 +104,7 @@ pub fn score(guess: &Word, soln: &Word) -> u8 {
         if g == s {
             continue;
         } else if misplaced[g as usize] > 0 {
-            value += AMBER * powers[i];
+            value += AMBER as Score * powers[i];
             misplaced[g as usize] -= 1;
         }
     }
@@ -98,8 +164,8 @@ mod tests {
         #[case] ternary_score: &str,
     ) {
         // Arrange
-        let guess = Word::new(guess);
-        let soln = Word::new(soln);
+        let guess: Word = guess.parse().unwrap();
+        let soln: Word = soln.parse().unwrap();
         let expected = str_to_score(ternary_score);
 
         // Act
@@ -110,13 +176,108 @@ mod tests {
     }
 
     #[rstest]
-    fn convert_ternary__both_ways__roundtrips() {
-        for i in 0..MAX_SCORE {
+    #[case("01020", "01020")]
+    #[case("22222", "22222")]
+    #[case("BYBGB", "01020")]
+    #[case("gyxwa", "21001")]
+    #[case(" 00000\n", "00000")]
+    fn parse_score__valid_input__parses(#[case] input: &str, #[case] ternary_score: &str) {
+        // Arrange
+        let expected = str_to_score(ternary_score);
+
+        // Act
+        let actual = parse_score(input, 5);
+
+        // Assert
+        assert_eq!(Some(expected), actual);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("0102")]
+    #[case("010200")]
+    #[case("01320")]
+    #[case("SALET")]
+    fn parse_score__invalid_input__returns_none(#[case] input: &str) {
+        // Act
+        let actual = parse_score(input, 5);
+
+        // Assert
+        assert_eq!(None, actual);
+    }
+
+    #[rstest]
+    #[case("SPEAR", "STRIP", "20101", true)]
+    #[case("SPRIG", "STRIP", "20101", true)]
+    #[case("SUPER", "STRIP", "20101", true)]
+    #[case("PARSE", "STRIP", "20101", false)]
+    #[case("SPEAK", "STRIP", "20101", false)]
+    #[case("CRANE", "SALET", "00000", true)]
+    #[case("GAMMA", "MUMMY", "00220", true)]
+    #[case("MAGMA", "MUMMY", "00220", false)]
+    #[case("ERROR", "AGREE", "01102", false)]
+    #[case("GRAVE", "AGREE", "01102", true)]
+    #[case("ABBOT", "ABBEY", "02100", true)]
+    #[case("OBESE", "ABBEY", "02100", false)]
+    fn satisfies_hard_mode__all_cases__checks_correctly(
+        #[case] guess: &str,
+        #[case] prev_guess: &str,
+        #[case] ternary_score: &str,
+        #[case] expected: bool,
+    ) {
+        // Arrange
+        let guess: Word = guess.parse().unwrap();
+        let prev_guess: Word = prev_guess.parse().unwrap();
+        let prev_score = str_to_score(ternary_score);
+
+        // Act
+        let actual = satisfies_hard_mode(&guess, &prev_guess, prev_score);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("SOUP", "SPUD", "2021")]
+    #[case("SPUD", "SPUD", "2222")]
+    #[case("ANANAS", "BANANA", "111110")]
+    #[case("SPACES", "SPICES", "220222")]
+    #[case("SCALPEL", "CALIPER", "0111220")]
+    #[case("CALIPER", "CALIPER", "2222222")]
+    fn score_word__other_lengths__scores_correctly(
+        #[case] guess: &str,
+        #[case] soln: &str,
+        #[case] ternary_score: &str,
+    ) {
+        // Arrange
+        let guess: Word = guess.parse().unwrap();
+        let soln: Word = soln.parse().unwrap();
+        let expected = str_to_score(ternary_score);
+
+        // Act
+        let actual = score(&guess, &soln);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    fn parse_score__for_seven_letters__parses() {
+        // Act
+        let actual = parse_score("GYBBGGY", 7);
+
+        // Assert
+        assert_eq!(Some(str_to_score("2100221")), actual);
+    }
+
+    #[rstest]
+    fn convert_ternary__both_ways__roundtrips(#[values(4, 5, 7)] size: usize) {
+        for i in 0..max_score(size) {
             // Arrange
-            let expected = i as u8;
+            let expected = i;
 
             // Act
-            let ternary = score_to_str(expected);
+            let ternary = score_to_str(expected, size);
             let actual = str_to_score(&ternary);
 
             // Assert
@@ -0,0 +1,314 @@
+use clap::ValueEnum;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::guess::Algorithm;
+use crate::scoring;
+use crate::solver::Solver;
+use crate::word::Word;
+
+/// What an exhaustive search should minimise.
+#[derive(ValueEnum, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    /// The mean number of guesses over every remaining answer.
+    #[default]
+    Expected,
+
+    /// The most guesses any remaining answer could need.
+    WorstCase,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    pub objective: Objective,
+
+    /// Number of the heuristic's best guesses to try at each position.
+    pub top_k: usize,
+
+    /// The most guesses the search may spend solving any answer.
+    pub max_depth: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        SearchOptions {
+            objective: Objective::Expected,
+            top_k: 10,
+            max_depth: 6,
+        }
+    }
+}
+
+/// The cost of optimal play and the guess that achieves it.
+pub type Plan = (u32, Word);
+
+/// The remaining solutions, the guesses hard mode still allows, and the number of
+/// guesses left to solve them in.
+type Subproblem = (Vec<usize>, Option<Vec<usize>>, usize);
+
+/// A depth-limited search for the guess that minimises the number of guesses
+/// still needed, trying only the solver's best few candidates at each position.
+///
+/// Costs are the total number of guesses over every remaining answer for
+/// `Objective::Expected`, and the largest number for `Objective::WorstCase`.
+pub struct TreeSearch {
+    options: SearchOptions,
+    all_guesses: Vec<usize>,
+
+    /// Restricts the guesses after each score to those that use every hint revealed.
+    hard_mode: bool,
+    memo: Mutex<HashMap<Subproblem, Option<Plan>>>,
+}
+
+impl TreeSearch {
+    pub fn new(options: SearchOptions, num_guesses: usize, hard_mode: bool) -> TreeSearch {
+        TreeSearch {
+            options,
+            all_guesses: (0..num_guesses).collect(),
+            hard_mode,
+            memo: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Finds the optimal guess from `guesses`, after `num_played` guesses have already
+    /// been made. Returns `None` if the search cannot guarantee to solve every answer
+    /// within the depth limit, which counts every guess of the game.
+    pub fn best_guess<T: Algorithm>(
+        &self,
+        solver: &Solver<T>,
+        guesses: &[usize],
+        potential_solns: &[usize],
+        num_played: usize,
+    ) -> Option<Plan> {
+        let depth = self.options.max_depth.saturating_sub(num_played);
+        self.plan(solver, guesses, potential_solns, depth)
+    }
+
+    fn search<T: Algorithm>(
+        &self,
+        solver: &Solver<T>,
+        guesses: &[usize],
+        potential_solns: &[usize],
+        depth: usize,
+    ) -> Option<Plan> {
+        let hard_guesses = self.hard_mode.then(|| guesses.to_vec());
+        let key = (potential_solns.to_vec(), hard_guesses, depth);
+        if let Some(plan) = self.memo.lock().unwrap().get(&key) {
+            return plan.clone();
+        }
+
+        let plan = self.plan(solver, guesses, potential_solns, depth);
+        self.memo.lock().unwrap().insert(key, plan.clone());
+        plan
+    }
+
+    fn plan<T: Algorithm>(
+        &self,
+        solver: &Solver<T>,
+        guesses: &[usize],
+        potential_solns: &[usize],
+        depth: usize,
+    ) -> Option<Plan> {
+        let num_solns = potential_solns.len();
+
+        // One or two answers are best solved by guessing one of them
+        if num_solns <= 2 {
+            if depth < num_solns {
+                return None;
+            }
+
+            let guess = solver.dictionary().potential_solns[potential_solns[0]].clone();
+            let cost = match self.options.objective {
+                Objective::Expected => 2 * num_solns as u32 - 1,
+                Objective::WorstCase => num_solns as u32,
+            };
+            return Some((cost, guess));
+        }
+
+        if depth < 2 {
+            return None;
+        }
+
+        let all_words = &solver.dictionary().all_words;
+        let max_score = scoring::max_score(solver.dictionary().size());
+        let mut best: Option<Plan> = None;
+
+        for guess in solver.ranked_guesses(guesses, potential_solns, self.options.top_k) {
+            let guess = &all_words[guess];
+            let partitions = solver.partition(guess, potential_solns);
+
+            // A guess that cannot tell any answers apart never makes progress
+            if partitions.len() == 1 && !partitions.contains_key(&max_score) {
+                continue;
+            }
+
+            let mut cost = 0;
+            for (score, partition) in partitions {
+                let hard_guesses: Vec<usize>;
+                let next_guesses = if self.hard_mode {
+                    hard_guesses = guesses
+                        .iter()
+                        .copied()
+                        .filter(|&next| {
+                            scoring::satisfies_hard_mode(&all_words[next], guess, score)
+                        })
+                        .collect();
+                    &hard_guesses
+                } else {
+                    &self.all_guesses
+                };
+
+                let part_cost = if score == max_score {
+                    1
+                } else {
+                    match self.search(solver, next_guesses, &partition, depth - 1) {
+                        Some((sub_cost, _)) => match self.options.objective {
+                            Objective::Expected => partition.len() as u32 + sub_cost,
+                            Objective::WorstCase => 1 + sub_cost,
+                        },
+                        None => {
+                            cost = u32::MAX;
+                            break;
+                        }
+                    }
+                };
+
+                cost = match self.options.objective {
+                    Objective::Expected => cost + part_cost,
+                    Objective::WorstCase => cost.max(part_cost),
+                };
+
+                // Stop as soon as this guess can no longer beat the best so far
+                if matches!(&best, Some((best_cost, _)) if cost >= *best_cost) {
+                    break;
+                }
+            }
+
+            if cost != u32::MAX && !matches!(&best, Some((best_cost, _)) if cost >= *best_cost) {
+                best = Some((cost, guess.clone()));
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::dictionary::Dictionary;
+    use crate::fixtures::skill_family;
+    use crate::guess::EntropyAlgorithm;
+    use crate::reporting::NullReporter;
+    use crate::solver::SolverOptions;
+    use rstest::{fixture, rstest};
+
+    #[rstest]
+    #[case(Objective::Expected, 8)]
+    #[case(Objective::WorstCase, 2)]
+    fn best_guess__for_skill_family__splits_every_answer(
+        solver: Solver<EntropyAlgorithm>,
+        #[case] objective: Objective,
+        #[case] expected_cost: u32,
+    ) {
+        // Arrange
+        let options = SearchOptions {
+            objective,
+            ..Default::default()
+        };
+        let sut = TreeSearch::new(options, 5, false);
+        let guesses: Vec<usize> = (0..5).collect();
+
+        // Act
+        let actual = sut.best_guess(&solver, &guesses, &[0, 1, 2, 3], 0);
+
+        // Assert
+        let (cost, guess) = actual.unwrap();
+        assert_eq!(expected_cost, cost);
+        assert_eq!("KAPOW", guess.value());
+    }
+
+    #[rstest]
+    fn best_guess__with_only_answers__guesses_in_turn(solver: Solver<EntropyAlgorithm>) {
+        // Arrange
+        let sut = TreeSearch::new(SearchOptions::default(), 5, false);
+
+        // Act
+        let actual = sut.best_guess(&solver, &[1, 2, 3, 4], &[0, 1, 2, 3], 0);
+
+        // Assert
+        // Guessing in turn: 1 + 2 + 3 + 4
+        let (cost, _) = actual.unwrap();
+        assert_eq!(10, cost);
+    }
+
+    #[rstest]
+    fn best_guess__beyond_depth_limit__returns_none(solver: Solver<EntropyAlgorithm>) {
+        // Arrange
+        let options = SearchOptions {
+            max_depth: 2,
+            ..Default::default()
+        };
+        let sut = TreeSearch::new(options, 5, false);
+
+        // Act
+        let actual = sut.best_guess(&solver, &[1, 2, 3, 4], &[0, 1, 2, 3], 0);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[rstest]
+    fn best_guess__after_guesses_played__counts_them_against_depth(
+        solver: Solver<EntropyAlgorithm>,
+    ) {
+        // Arrange
+        let sut = TreeSearch::new(SearchOptions::default(), 5, false);
+
+        // Act
+        let actual = sut.best_guess(&solver, &[1, 2, 3, 4], &[0, 1, 2, 3], 4);
+
+        // Assert
+        // An answer and then KAPOW needs three guesses, but only two of the six remain
+        assert!(actual.is_none());
+        assert!(sut
+            .best_guess(&solver, &[1, 2, 3, 4], &[0, 1, 2, 3], 3)
+            .is_some());
+    }
+
+    #[rstest]
+    #[case(false, 3)]
+    #[case(true, 4)]
+    fn best_guess__in_hard_mode__only_plans_permitted_guesses(
+        solver: Solver<EntropyAlgorithm>,
+        #[case] hard_mode: bool,
+        #[case] expected_cost: u32,
+    ) {
+        // Arrange
+        let options = SearchOptions {
+            objective: Objective::WorstCase,
+            ..Default::default()
+        };
+        let sut = TreeSearch::new(options, 5, hard_mode);
+
+        // Act
+        let actual = sut.best_guess(&solver, &[1, 2, 3, 4], &[0, 1, 2, 3], 0);
+
+        // Assert
+        // Once an -ILL word is guessed, hard mode rules out KAPOW, which splits the rest apart
+        let (cost, _) = actual.unwrap();
+        assert_eq!(expected_cost, cost);
+    }
+
+    #[fixture]
+    fn solver(skill_family: Dictionary) -> Solver<EntropyAlgorithm> {
+        Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            skill_family,
+            SolverOptions::default(),
+        )
+    }
+}
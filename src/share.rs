@@ -0,0 +1,399 @@
+use clap::ValueEnum;
+use rayon::prelude::*;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::boards::{Scoreboard, MAX_GUESSES};
+use crate::dictionary::Dictionary;
+use crate::guess::GuessMetrics;
+use crate::reporting::{ConsoleReporter, Reporter};
+use crate::scoring::{self, Score};
+use crate::word::{Word, MAX_SIZE};
+
+/// Tells the text emoji presentation to use, which some apps append to each square.
+const VARIATION_SELECTOR: char = '\u{fe0f}';
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ShareError {
+    /// The text has no rows of squares.
+    Empty,
+
+    /// A row mixes squares with other characters.
+    InvalidRow(String),
+
+    /// A row has more squares than the longest word supported.
+    TooLong(String),
+
+    /// A row does not have as many squares as the first row.
+    WrongLength { row: String, expected: usize },
+}
+
+impl Display for ShareError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ShareError::Empty => write!(f, "No rows of squares were found in the grid"),
+            ShareError::InvalidRow(row) => {
+                write!(f, "'{row}' is not a row of green, yellow and grey squares")
+            }
+            ShareError::TooLong(row) => {
+                write!(f, "'{row}' has more than {MAX_SIZE} squares")
+            }
+            ShareError::WrongLength { row, expected } => {
+                write!(f, "'{row}' does not have {expected} squares")
+            }
+        }
+    }
+}
+
+impl Error for ShareError {}
+
+/// The scores from a grid of emoji squares, as shared after a game of Wordle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareGrid {
+    pub scores: Vec<Score>,
+    size: usize,
+}
+
+impl ShareGrid {
+    /// The number of letters in each guess.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The words that could have been played for each row, were `soln` the answer.
+    pub fn guesses(&self, dictionary: &Dictionary, soln: &Word) -> Vec<Vec<Word>> {
+        self.scores
+            .iter()
+            .map(|&score| {
+                dictionary
+                    .all_words
+                    .iter()
+                    .filter(|guess| scoring::score(guess, soln) == score)
+                    .cloned()
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The answers for which some word could have been played for every row.
+    pub fn answers(&self, dictionary: &Dictionary) -> Vec<Word> {
+        dictionary
+            .potential_solns
+            .par_iter()
+            .filter(|soln| {
+                let mut is_possible = vec![false; scoring::num_scores(self.size)];
+                for guess in &dictionary.all_words {
+                    is_possible[scoring::score(guess, soln) as usize] = true;
+                }
+
+                self.scores.iter().all(|&score| is_possible[score as usize])
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+impl FromStr for ShareGrid {
+    type Err = ShareError;
+
+    /// Parses every line of squares, where 🟩 or 🟧 is green, 🟨 or 🟦 is yellow and
+    /// ⬛ or ⬜ is grey. Lines without any squares, such as the header, are skipped.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut scores = Vec::new();
+        let mut size = None;
+
+        for line in value.lines() {
+            let row = line.trim();
+            let digits: Vec<Option<char>> = row
+                .chars()
+                .filter(|&c| c != VARIATION_SELECTOR && !c.is_whitespace())
+                .map(|c| match c {
+                    '⬛' | '⬜' => Some('0'),
+                    '🟨' | '🟦' => Some('1'),
+                    '🟩' | '🟧' => Some('2'),
+                    _ => None,
+                })
+                .collect();
+
+            if digits.iter().all(Option::is_none) {
+                continue;
+            }
+
+            let Some(ternary) = digits.into_iter().collect::<Option<String>>() else {
+                return Err(ShareError::InvalidRow(row.to_string()));
+            };
+
+            match size {
+                None if ternary.len() > MAX_SIZE => {
+                    return Err(ShareError::TooLong(row.to_string()))
+                }
+                None => size = Some(ternary.len()),
+                Some(expected) if expected != ternary.len() => {
+                    return Err(ShareError::WrongLength {
+                        row: row.to_string(),
+                        expected,
+                    })
+                }
+                Some(_) => {}
+            }
+
+            scores.push(scoring::str_to_score(&ternary));
+        }
+
+        match size {
+            Some(size) => Ok(ShareGrid { scores, size }),
+            None => Err(ShareError::Empty),
+        }
+    }
+}
+
+/// The background the grid will be pasted onto, which decides the colour of grey squares.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+}
+
+/// Writes each game as the grid of emoji squares that Wordle shares, which
+/// unlike a table of ANSI colours can be pasted into chat.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShareReporter {
+    pub theme: Theme,
+
+    /// Uses orange and blue squares in place of green and yellow.
+    pub high_contrast: bool,
+
+    /// Marks the header with an asterisk, as Wordle does for hard mode games.
+    pub hard_mode: bool,
+}
+
+impl ShareReporter {
+    /// The grey, yellow and green squares, indexed by the digits of a ternary score.
+    fn squares(&self) -> [char; 3] {
+        let grey = match self.theme {
+            Theme::Dark => '⬛',
+            Theme::Light => '⬜',
+        };
+
+        if self.high_contrast {
+            [grey, '🟦', '🟧']
+        } else {
+            [grey, '🟨', '🟩']
+        }
+    }
+
+    pub fn report(&self, scoreboard: &Scoreboard) -> String {
+        let num_guesses = match scoreboard.len() {
+            n if scoreboard.is_solved() && n <= MAX_GUESSES => n.to_string(),
+            _ => "X".into(),
+        };
+        let asterisk = if self.hard_mode { "*" } else { "" };
+        let mut lines = vec![
+            format!("Wordle {num_guesses}/{MAX_GUESSES}{asterisk}"),
+            "".into(),
+        ];
+
+        let squares = self.squares();
+        for row in &scoreboard.rows {
+            let ternary = scoring::score_to_str(row.score, row.guess.len());
+            let row: String = ternary
+                .bytes()
+                .map(|digit| squares[(digit - b'0') as usize])
+                .collect();
+            lines.push(row);
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl Reporter for ShareReporter {
+    fn print(&self, scoreboard: &Scoreboard) {
+        println!("{}", self.report(scoreboard));
+    }
+
+    // The grid is only shared once a game is over
+    fn print_tail(&self, _scoreboard: &Scoreboard) {}
+    fn report_start(&self, _soln: &Word) {}
+
+    fn report_success(&self, scoreboard: &Scoreboard, _elapsed: Duration) {
+        println!("{}\n", self.report(scoreboard));
+    }
+
+    fn report_failure(&self, scoreboard: &Scoreboard) {
+        println!("{}\n", self.report(scoreboard));
+    }
+
+    fn report_suggestions(&self, suggestions: &[GuessMetrics]) {
+        ConsoleReporter.report_suggestions(suggestions);
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::fixtures::dictionary;
+    use rstest::{fixture, rstest};
+
+    #[rstest]
+    #[case("Wordle 1,234 3/6*\n\n⬛⬛⬛🟩⬛\n⬛🟩🟩🟩🟩\n🟩🟩🟩🟩🟩")]
+    #[case("⬜⬜⬜🟩⬜\n⬜🟩🟩🟩🟩\n🟩🟩🟩🟩🟩\n")]
+    #[case("⬛\u{fe0f}⬛\u{fe0f}⬛\u{fe0f}🟧⬛\u{fe0f}\n⬛🟧🟧🟧 🟧\n  🟧🟧🟧🟧🟧")]
+    fn from_str__for_each_style__parses_scores(#[case] value: &str) {
+        // Act
+        let actual: ShareGrid = value.parse().unwrap();
+
+        // Assert
+        let expected: Vec<Score> = ["00020", "02222", "22222"]
+            .iter()
+            .map(|ternary| scoring::str_to_score(ternary))
+            .collect();
+        assert_eq!(expected, actual.scores);
+        assert_eq!(5, actual.size());
+    }
+
+    #[rstest]
+    fn from_str__for_yellow_squares__parses_ambers() {
+        // Act
+        let actual: ShareGrid = "🟨🟦⬛⬛".parse().unwrap();
+
+        // Assert
+        assert_eq!(vec![scoring::str_to_score("1100")], actual.scores);
+        assert_eq!(4, actual.size());
+    }
+
+    #[rstest]
+    #[case("", ShareError::Empty)]
+    #[case("Wordle 1,234 X/6", ShareError::Empty)]
+    #[case("🟩🟩x🟩🟩", ShareError::InvalidRow("🟩🟩x🟩🟩".into()))]
+    #[case("🟩🟩🟩🟩🟩🟩🟩🟩🟩🟩🟩", ShareError::TooLong("🟩🟩🟩🟩🟩🟩🟩🟩🟩🟩🟩".into()))]
+    #[case("🟩🟩🟩🟩🟩\n🟩🟩🟩🟩", ShareError::WrongLength { row: "🟩🟩🟩🟩".into(), expected: 5 })]
+    fn from_str__for_bad_grid__errors(#[case] value: &str, #[case] expected: ShareError) {
+        // Act
+        let actual = value.parse::<ShareGrid>();
+
+        // Assert
+        assert_eq!(Err(expected), actual);
+    }
+
+    #[rstest]
+    fn guesses__for_answer__lists_words_for_each_row(dictionary: Dictionary, grid: ShareGrid) {
+        // Act
+        let actual = grid.guesses(&dictionary, &"POWER".parse().unwrap());
+
+        // Assert
+        let expected: Vec<Vec<Word>> = [vec!["SALET"], vec!["TOWER", "ROWER"], vec!["POWER"]]
+            .iter()
+            .map(|words| words.iter().map(|w| w.parse().unwrap()).collect())
+            .collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    fn answers__without_answer__lists_consistent_answers(dictionary: Dictionary, grid: ShareGrid) {
+        // Act
+        let actual = grid.answers(&dictionary);
+
+        // Assert
+        // No guess turns a single letter of TOWER green but leaves the rest grey
+        let expected: Vec<Word> = vec!["ROWER".parse().unwrap(), "POWER".parse().unwrap()];
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(
+        Theme::Dark,
+        false,
+        false,
+        "Wordle 3/6\n\n⬛⬛⬛🟩⬛\n⬛🟩🟩🟩🟩\n🟩🟩🟩🟩🟩"
+    )]
+    #[case(
+        Theme::Light,
+        false,
+        true,
+        "Wordle 3/6*\n\n⬜⬜⬜🟩⬜\n⬜🟩🟩🟩🟩\n🟩🟩🟩🟩🟩"
+    )]
+    #[case(
+        Theme::Dark,
+        true,
+        false,
+        "Wordle 3/6\n\n⬛⬛⬛🟧⬛\n⬛🟧🟧🟧🟧\n🟧🟧🟧🟧🟧"
+    )]
+    fn report__for_each_style__builds_grid(
+        scoreboard: Scoreboard,
+        #[case] theme: Theme,
+        #[case] high_contrast: bool,
+        #[case] hard_mode: bool,
+        #[case] expected: &str,
+    ) {
+        // Arrange
+        let sut = ShareReporter {
+            theme,
+            high_contrast,
+            hard_mode,
+        };
+
+        // Act
+        let actual = sut.report(&scoreboard);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    fn report__then_parse__roundtrips(
+        scoreboard: Scoreboard,
+        grid: ShareGrid,
+        #[values(Theme::Dark, Theme::Light)] theme: Theme,
+        #[values(false, true)] high_contrast: bool,
+    ) {
+        // Arrange
+        let sut = ShareReporter {
+            theme,
+            high_contrast,
+            hard_mode: false,
+        };
+
+        // Act
+        let actual: ShareGrid = sut.report(&scoreboard).parse().unwrap();
+
+        // Assert
+        assert_eq!(grid, actual);
+    }
+
+    #[rstest]
+    fn report__when_unsolved__shows_x(mut scoreboard: Scoreboard) {
+        // Arrange
+        scoreboard.rows.pop();
+        let sut = ShareReporter::default();
+
+        // Act
+        let actual = sut.report(&scoreboard);
+
+        // Assert
+        assert!(actual.starts_with("Wordle X/6\n\n"));
+    }
+
+    #[fixture]
+    fn scoreboard() -> Scoreboard {
+        let soln: Word = "POWER".parse().unwrap();
+        let mut scoreboard = Scoreboard::default();
+        for (guess, num_left) in [("SALET", 2), ("TOWER", 1), ("POWER", 1)] {
+            let guess: Word = guess.parse().unwrap();
+            let score = scoring::score(&guess, &soln);
+            scoreboard.add_row(Some(soln.clone()), guess, score, num_left);
+        }
+
+        scoreboard
+    }
+
+    #[fixture]
+    fn grid() -> ShareGrid {
+        "⬛⬛⬛🟩⬛\n⬛🟩🟩🟩🟩\n🟩🟩🟩🟩🟩".parse().unwrap()
+    }
+}
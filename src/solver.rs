@@ -1,76 +1,402 @@
+use crate::absurdle::AbsurdleHost;
+use crate::analysis::Analysis;
 use crate::boards::Scoreboard;
-use crate::dictionary::Dictionary;
-use crate::guess::Algorithm;
+use crate::cache::OpeningCache;
+use crate::dictionary::{self, Dictionary, Priors};
+use crate::guess::{Algorithm, EntropyAlgorithm, GuessMetrics};
+use crate::lookahead::Lookahead;
+use crate::matrix::{MatrixSource, ScoreMatrix};
 use crate::reporting::Reporter;
 use crate::scoring;
-use crate::scoring::MAX_SCORE;
+use crate::scoring::Score;
+use crate::search::{SearchOptions, TreeSearch};
+use crate::tree::DecisionTree;
 use crate::word::Word;
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::collections::BTreeMap;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Instant;
+
+pub const MAX_ITERS: usize = 20;
 
 pub trait Solve {
-    fn solve(&self, soln: &Word, opening_guess: Word) -> Option<Scoreboard>;
+    fn solve(&self, soln: &Word, opening_guess: Option<Word>) -> Option<Scoreboard>;
+    fn solve_interactive(&self, opening_guess: Option<Word>) -> Option<Scoreboard>;
+    fn solve_adversarial(&self, opening_guess: Option<Word>) -> Option<Scoreboard>;
+    fn host_absurdle(&self) -> Option<Scoreboard>;
+    fn opening_guess(&self) -> Word;
+    fn openers(&self, n: usize) -> Vec<GuessMetrics>;
+    fn replies(&self, opening_guess: &Word) -> BTreeMap<Score, Word>;
+    fn analyse(&self, soln: &Word, guesses: &[Word]) -> Analysis;
+    fn decision_tree(&self, opening_guess: Option<Word>) -> DecisionTree;
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct SolverOptions {
+    /// Restricts each guess to words that use every hint revealed so far.
+    pub hard_mode: bool,
+
+    /// Number of threads used to evaluate guesses. Zero uses every available core.
+    pub num_threads: usize,
+
+    /// Whether to precompute the score of every guess against every solution.
+    pub matrix: MatrixSource,
+
+    /// Search for optimal play instead of taking the algorithm's best guess.
+    pub search: Option<SearchOptions>,
+
+    /// Rank the algorithm's best guesses by looking two guesses ahead.
+    pub lookahead: Option<Lookahead>,
+
+    /// Number of the best guesses to report, with their metrics, before each guess.
+    pub num_suggestions: usize,
+
+    /// How likely each word is to be the answer. Without priors, every answer is equally likely.
+    pub priors: Option<Priors>,
+
+    /// A file to save the first and second guesses to, and look them up from in later runs.
+    pub cache: Option<PathBuf>,
 }
 
 pub struct Solver<T> {
     algorithm: T,
     reporter: Box<dyn Reporter>,
     dictionary: Dictionary,
+    options: SolverOptions,
+    pool: ThreadPool,
+    matrix: Option<ScoreMatrix>,
+    search: Option<TreeSearch>,
+    weights: Option<Vec<f64>>,
+    cache: Option<Mutex<OpeningCache>>,
+    cache_key: String,
 }
 
 impl<T: Algorithm> Solver<T> {
-    pub fn new(algorithm: T, reporter: Box<dyn Reporter>, dictionary: Dictionary) -> Solver<T> {
+    pub fn new(
+        algorithm: T,
+        reporter: Box<dyn Reporter>,
+        dictionary: Dictionary,
+        options: SolverOptions,
+    ) -> Solver<T> {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(options.num_threads)
+            .build()
+            .expect("Unable to build thread pool");
+
+        let matrix = pool.install(|| ScoreMatrix::from_source(&options.matrix, &dictionary));
+        let search = options
+            .search
+            .clone()
+            .map(|search| TreeSearch::new(search, dictionary.all_words.len(), options.hard_mode));
+        let weights = options
+            .priors
+            .as_ref()
+            .map(|priors| priors.weights(&dictionary.potential_solns));
+        let cache = options
+            .cache
+            .as_ref()
+            .map(|path| Mutex::new(OpeningCache::load(path)));
+        let cache_key = cache_key::<T>(&dictionary, &options, weights.as_deref());
+
         Solver {
             algorithm,
             reporter,
             dictionary,
+            options,
+            pool,
+            matrix,
+            search,
+            weights,
+            cache,
+            cache_key,
         }
     }
 
-    pub fn run(&self, soln: &Word, opening_guess: Word) -> Option<Scoreboard> {
-        println!("Loading dictionaries...");
-        let &Dictionary {
-            ref all_words,
-            ref potential_solns,
-        } = &self.dictionary;
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.dictionary
+    }
+
+    /// Solves for a known `soln`. Without an opening guess, the solver picks its own.
+    pub fn run(&self, soln: &Word, opening_guess: Option<Word>) -> Option<Scoreboard> {
+        self.reporter.report_start(soln);
+        let now = Instant::now();
+
+        let scoreboard = self.play(Some(soln), opening_guess, |guess| {
+            Some(scoring::score(guess, soln))
+        })?;
+
+        self.reporter.report_success(&scoreboard, now.elapsed());
+        Some(scoreboard)
+    }
+
+    pub fn run_interactive(
+        &self,
+        opening_guess: Option<Word>,
+        input: &mut impl BufRead,
+    ) -> Option<Scoreboard> {
+        eprintln!("Begin interactive solve...");
+        eprintln!(
+            "Enter each score as a ternary string (e.g. 01020) or as colours (e.g. BYBGB).\n"
+        );
 
-        let mut potential_solns: Vec<Word> = potential_solns.to_vec();
+        let scoreboard = self.play(None, opening_guess, |guess| {
+            let prompt = format!("Guess {guess} and enter the score: ");
+            read_score(&prompt, guess.len(), input)
+        })?;
+        eprintln!();
+        self.reporter.print(&scoreboard);
+        Some(scoreboard)
+    }
 
-        println!("Begin solve for solution {soln}...\n");
-        use std::time::Instant;
+    /// Plays against an Absurdle host, which keeps as many answers alive as it can.
+    pub fn run_adversarial(&self, opening_guess: Option<Word>) -> Option<Scoreboard> {
+        let mut host = AbsurdleHost::new(self);
         let now = Instant::now();
-        let mut guess = opening_guess;
+
+        let scoreboard = self.play(None, opening_guess, |guess| Some(host.respond(guess)))?;
+
+        self.reporter.report_success(&scoreboard, now.elapsed());
+        Some(scoreboard)
+    }
+
+    fn play(
+        &self,
+        soln: Option<&Word>,
+        opening_guess: Option<Word>,
+        mut observe: impl FnMut(&Word) -> Option<Score>,
+    ) -> Option<Scoreboard> {
+        let mut potential_solns: Vec<usize> = (0..self.dictionary.potential_solns.len()).collect();
+        let mut guess = opening_guess.unwrap_or_else(|| Solve::opening_guess(self));
         let mut scoreboard: Scoreboard = Default::default();
 
-        const MAX_ITERS: i32 = 20;
         for _i in 0..MAX_ITERS {
-            let observed_score = scoring::score(&guess, soln);
+            if self.options.num_suggestions > 0 {
+                let guesses = self.candidate_guesses(&scoreboard);
+                let n = self.options.num_suggestions;
+                let suggestions = self.top_guesses(&guesses, &potential_solns, n);
+                self.reporter.report_suggestions(&suggestions);
+            }
+
+            let observed_score = observe(&guess)?;
             potential_solns = self.trim_solns(&guess, observed_score, &potential_solns);
-            scoreboard.add_row(soln.clone(), guess, observed_score, potential_solns.len());
+            scoreboard.add_row(soln.cloned(), guess, observed_score, potential_solns.len());
             self.reporter.print_tail(&scoreboard);
 
             if scoreboard.is_solved() {
-                let elapsed = now.elapsed();
-                println!("Elapsed: {:.2?}\n", elapsed);
                 return Some(scoreboard);
             }
 
-            guess = self.best_guess(all_words, &potential_solns).into();
+            if potential_solns.is_empty() {
+                self.reporter.report_failure(&scoreboard);
+                return None;
+            }
+
+            guess = self.next_guess(&scoreboard, &potential_solns);
         }
 
         self.reporter.report_failure(&scoreboard);
         None
     }
 
-    pub fn best_guess(&self, all_words: &[Word], potential_solns: &[Word]) -> T::TGuess {
+    /// Builds the complete strategy: the guess to play in response to every
+    /// score that could be observed, until every answer is solved.
+    pub fn decision_tree(&self, opening_guess: Option<Word>) -> DecisionTree {
+        let potential_solns: Vec<usize> = (0..self.dictionary.potential_solns.len()).collect();
+        let guess = opening_guess.unwrap_or_else(|| Solve::opening_guess(self));
+        self.grow_tree(guess, &potential_solns, &Scoreboard::default())
+    }
+
+    fn grow_tree(
+        &self,
+        guess: Word,
+        potential_solns: &[usize],
+        scoreboard: &Scoreboard,
+    ) -> DecisionTree {
+        let solns = &self.dictionary.potential_solns;
+        let is_potential_soln = potential_solns.iter().any(|&soln| solns[soln] == guess);
+        let max_score = scoring::max_score(guess.len());
+        let mut branches = Vec::new();
+        let mut unsolved = 0;
+
+        for (score, partition) in self.partition(&guess, potential_solns) {
+            if score == max_score {
+                continue;
+            }
+
+            let mut scoreboard = scoreboard.clone();
+            scoreboard.add_row(None, guess.clone(), score, partition.len());
+            if scoreboard.len() >= MAX_ITERS {
+                unsolved += partition.len();
+                continue;
+            }
+
+            let next_guess = self.next_guess(&scoreboard, &partition);
+            let branch = self.grow_tree(next_guess, &partition, &scoreboard);
+            branches.push((score, branch));
+        }
+
+        DecisionTree {
+            guess,
+            num_solns: potential_solns.len(),
+            is_potential_soln,
+            branches,
+            unsolved,
+        }
+    }
+
+    fn next_guess(&self, scoreboard: &Scoreboard, potential_solns: &[usize]) -> Word {
+        // The second guess only depends on the first, so may have been cached
+        if let (Some(_), [row]) = (&self.cache, scoreboard.rows.as_slice()) {
+            if let Some(guess) = self.replies(&row.guess).remove(&row.score) {
+                return guess;
+            }
+        }
+
+        let guesses = self.candidate_guesses(scoreboard);
+        self.choose_guess(&guesses, potential_solns, scoreboard.len())
+    }
+
+    /// The solver's own opening guess, from the cache if it has chosen one before.
+    pub fn opening_guess(&self) -> Word {
+        let cached = self.cached(|cache| cache.get(&self.cache_key)?.opening.clone());
+        if let Some(guess) = cached.flatten() {
+            return guess;
+        }
+
+        let all_guesses: Vec<usize> = (0..self.dictionary.all_words.len()).collect();
+        let potential_solns: Vec<usize> = (0..self.dictionary.potential_solns.len()).collect();
+        let guess = self.choose_guess(&all_guesses, &potential_solns, 0);
+
+        self.update_cache(|cache| cache.entry(&self.cache_key).opening = Some(guess.clone()));
+        guess
+    }
+
+    /// The second guess after `opening_guess` for every score it could receive,
+    /// except a win. These are cached along with the opening guess.
+    pub fn replies(&self, opening_guess: &Word) -> BTreeMap<Score, Word> {
+        let cached = self.cached(|cache| {
+            let openings = cache.get(&self.cache_key)?;
+            openings.replies.get(opening_guess).cloned()
+        });
+        if let Some(replies) = cached.flatten() {
+            return replies;
+        }
+
+        let potential_solns: Vec<usize> = (0..self.dictionary.potential_solns.len()).collect();
+        let max_score = scoring::max_score(opening_guess.len());
+        let mut replies = BTreeMap::new();
+
+        for (score, partition) in self.partition(opening_guess, &potential_solns) {
+            if score == max_score {
+                continue;
+            }
+
+            let mut scoreboard = Scoreboard::default();
+            scoreboard.add_row(None, opening_guess.clone(), score, partition.len());
+            let guesses = self.candidate_guesses(&scoreboard);
+            replies.insert(
+                score,
+                self.choose_guess(&guesses, &partition, scoreboard.len()),
+            );
+        }
+
+        self.update_cache(|cache| {
+            let openings = cache.entry(&self.cache_key);
+            openings
+                .replies
+                .insert(opening_guess.clone(), replies.clone());
+        });
+        replies
+    }
+
+    fn cached<R>(&self, lookup: impl FnOnce(&OpeningCache) -> R) -> Option<R> {
+        let cache = self.cache.as_ref()?;
+        Some(lookup(&cache.lock().unwrap()))
+    }
+
+    fn update_cache(&self, update: impl FnOnce(&mut OpeningCache)) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+
+        let mut cache = cache.lock().unwrap();
+        update(&mut cache);
+        if let Err(error) = cache.save() {
+            eprintln!(
+                "Unable to save opening guesses to {}: {error}",
+                cache.path().display()
+            );
+        }
+    }
+
+    /// The positions of the words that may be guessed next.
+    pub fn candidate_guesses(&self, scoreboard: &Scoreboard) -> Vec<usize> {
+        if self.options.hard_mode {
+            self.permitted_guesses(scoreboard)
+        } else {
+            (0..self.dictionary.all_words.len()).collect()
+        }
+    }
+
+    /// Searches for optimal play or looks ahead if configured to, falling back to the
+    /// algorithm's best guess when the search cannot solve every answer within its depth limit.
+    /// The search only plans the guesses left after the `num_played` already made.
+    pub fn choose_guess(
+        &self,
+        guesses: &[usize],
+        potential_solns: &[usize],
+        num_played: usize,
+    ) -> Word {
+        let searched = self
+            .search
+            .as_ref()
+            .and_then(|search| search.best_guess(self, guesses, potential_solns, num_played))
+            .map(|(_, guess)| guess)
+            .or_else(|| {
+                let lookahead = self.options.lookahead?;
+                lookahead.best_guess(self, guesses, potential_solns)
+            });
+
+        match searched {
+            Some(guess) => guess,
+            None => self.best_guess(guesses, potential_solns).into(),
+        }
+    }
+
+    /// Picks the best guess from `guesses`, given as positions in `all_words`,
+    /// for the `potential_solns` that remain, given as positions in `potential_solns`.
+    pub fn best_guess(&self, guesses: &[usize], potential_solns: &[usize]) -> T::TGuess {
         if potential_solns.len() > 2 {
-            return self.all_guesses(all_words, potential_solns).min().unwrap();
+            let guesses = self.all_guesses(guesses, potential_solns);
+
+            // Break ties on dictionary order so that results do not depend on scheduling
+            let (guess, _) = self
+                .pool
+                .install(|| guesses.enumerate().map(|(i, g)| (g, i)).min())
+                .unwrap();
+
+            return guess;
         }
 
+        // Of the last two, play the likelier answer first
+        let soln = match &self.weights {
+            Some(weights) => potential_solns
+                .iter()
+                .copied()
+                .min_by(|&a, &b| weights[b].total_cmp(&weights[a]))
+                .unwrap(),
+            None => potential_solns[0],
+        };
+
         let num_solns = potential_solns.len();
-        let guess = &potential_solns[0];
+        let guess = &self.dictionary.potential_solns[soln];
 
         // Fake a histogram. Anything will do here...
-        let mut histogram = [0_u32; MAX_SCORE + 1];
-        histogram[MAX_SCORE] = 1;
+        let mut histogram = vec![0_u32; scoring::num_scores(self.dictionary.size())];
+        *histogram.last_mut().unwrap() = 1;
         if potential_solns.len() == 2 {
             histogram[0] = 1;
         }
@@ -78,35 +404,350 @@ impl<T: Algorithm> Solver<T> {
         self.algorithm.make_guess(guess, num_solns, &histogram)
     }
 
+    /// The positions of the `n` best guesses from `guesses`, best first.
+    pub fn ranked_guesses(
+        &self,
+        guesses: &[usize],
+        potential_solns: &[usize],
+        n: usize,
+    ) -> Vec<usize> {
+        let mut ranked: Vec<(T::TGuess, usize)> = self.pool.install(|| {
+            self.all_guesses(guesses, potential_solns)
+                .zip(guesses.par_iter().copied())
+                .collect()
+        });
+
+        ranked.sort();
+        ranked.into_iter().take(n).map(|(_, guess)| guess).collect()
+    }
+
+    /// The `n` best guesses from `guesses`, best first, with how each would
+    /// partition the `potential_solns`.
+    pub fn top_guesses(
+        &self,
+        guesses: &[usize],
+        potential_solns: &[usize],
+        n: usize,
+    ) -> Vec<GuessMetrics> {
+        self.ranked_guesses(guesses, potential_solns, n)
+            .into_iter()
+            .map(|guess| self.metrics(guess, potential_solns))
+            .collect()
+    }
+
+    /// How `guess`, given as a position in `all_words`, would partition the `potential_solns`.
+    /// With priors, the bits are weighted as they are when ranking guesses.
+    pub fn metrics(&self, guess: usize, potential_solns: &[usize]) -> GuessMetrics {
+        let histogram = self.histogram(guess, potential_solns);
+        let word = self.dictionary.all_words[guess].clone();
+        let mut metrics = GuessMetrics::from_histogram(word, &histogram);
+        if self.weights.is_some() {
+            metrics.entropy = self.weighted_entropy(guess, potential_solns, &histogram);
+        }
+
+        metrics
+    }
+
+    /// The bits of information `guess`, given as a position in `all_words`, gives about the
+    /// `potential_solns`, weighting each answer by its prior as the entropy algorithm does.
+    pub fn entropy(&self, guess: usize, potential_solns: &[usize]) -> f64 {
+        let histogram = self.histogram(guess, potential_solns);
+        self.weighted_entropy(guess, potential_solns, &histogram)
+    }
+
+    fn weighted_entropy(&self, guess: usize, potential_solns: &[usize], histogram: &[u32]) -> f64 {
+        let weighted = match &self.weights {
+            Some(weights) => self.weighted_histogram(guess, potential_solns, weights),
+            None => histogram.iter().map(|&count| count as f64).collect(),
+        };
+
+        let word = &self.dictionary.all_words[guess];
+        EntropyAlgorithm
+            .make_weighted_guess(word, histogram, &weighted)
+            .entropy()
+    }
+
+    /// The total prior weight of the `potential_solns`, or simply their number without priors.
+    pub fn weight(&self, potential_solns: &[usize]) -> f64 {
+        match &self.weights {
+            Some(weights) => potential_solns.iter().map(|&soln| weights[soln]).sum(),
+            None => potential_solns.len() as f64,
+        }
+    }
+
+    /// The `guesses` that may still be played once `guess` receives `score`.
+    pub fn remaining_guesses(&self, guesses: &[usize], guess: &Word, score: Score) -> Vec<usize> {
+        if !self.options.hard_mode {
+            return guesses.to_vec();
+        }
+
+        let all_words = &self.dictionary.all_words;
+        guesses
+            .iter()
+            .copied()
+            .filter(|&next| scoring::satisfies_hard_mode(&all_words[next], guess, score))
+            .collect()
+    }
+
+    /// The number of `guesses` that the algorithm ranks strictly better than `guess`.
+    pub fn num_better(&self, guess: usize, guesses: &[usize], potential_solns: &[usize]) -> usize {
+        let (guess, others): (Vec<T::TGuess>, Vec<T::TGuess>) = self.pool.install(|| {
+            (
+                self.all_guesses(&[guess], potential_solns).collect(),
+                self.all_guesses(guesses, potential_solns).collect(),
+            )
+        });
+
+        others.iter().filter(|other| **other < guess[0]).count()
+    }
+
+    /// Picks the best guess for several boards at once, where each board is
+    /// given as the positions of the `potential_solns` that remain on it.
+    pub fn best_combined_guess(&self, guesses: &[usize], boards: &[&[usize]]) -> T::TGuess {
+        let num_solns = boards.iter().map(|board| board.len()).sum();
+        let guesses = guesses.par_iter().map(|&guess| {
+            let histogram = self.combined_histogram(guess, boards);
+            let guess = &self.dictionary.all_words[guess];
+            self.algorithm.make_guess(guess, num_solns, &histogram)
+        });
+
+        let (guess, _) = self
+            .pool
+            .install(|| guesses.enumerate().map(|(i, g)| (g, i)).min())
+            .unwrap();
+
+        guess
+    }
+
+    /// Joins the non-empty buckets of each board's histogram, then appends the win
+    /// bucket of every board the guess would solve outright, or one empty bucket if none.
+    fn combined_histogram(&self, guess: usize, boards: &[&[usize]]) -> Vec<u32> {
+        let mut combined = Vec::new();
+        let mut wins = Vec::new();
+
+        for board in boards {
+            let mut histogram = self.histogram(guess, board);
+            let num_solved = histogram.pop().unwrap();
+            if num_solved > 0 {
+                wins.push(num_solved);
+            }
+            combined.extend(histogram.into_iter().filter(|&count| count > 0));
+        }
+
+        if wins.is_empty() {
+            wins.push(0);
+        }
+        combined.extend(wins);
+        combined
+    }
+
     fn all_guesses<'a>(
         &'a self,
-        all_words: &'a [Word],
-        potential_solns: &'a [Word],
-    ) -> impl Iterator<Item = T::TGuess> + 'a {
-        all_words.iter().map(move |guess| {
-            let mut histogram = [0_u32; MAX_SCORE + 1];
-            for potential_soln in potential_solns {
-                let score = scoring::score(guess, potential_soln) as usize;
-                histogram[score] += 1;
-            }
+        guesses: &'a [usize],
+        potential_solns: &'a [usize],
+    ) -> impl IndexedParallelIterator<Item = T::TGuess> + 'a {
+        guesses.par_iter().map(move |&guess| {
+            let histogram = self.histogram(guess, potential_solns);
             let num_solns = potential_solns.len();
-            self.algorithm.make_guess(guess, num_solns, &histogram)
+            let word = &self.dictionary.all_words[guess];
+
+            match &self.weights {
+                Some(weights) => {
+                    let weighted = self.weighted_histogram(guess, potential_solns, weights);
+                    self.algorithm
+                        .make_weighted_guess(word, &histogram, &weighted)
+                }
+                None => self.algorithm.make_guess(word, num_solns, &histogram),
+            }
         })
     }
 
-    fn trim_solns(&self, guess: &Word, observed_score: u8, potential_solns: &[Word]) -> Vec<Word> {
-        potential_solns
-            .iter()
-            .filter(|soln| scoring::score(guess, soln) == observed_score)
-            .cloned()
+    /// The total weight of the `potential_solns` that would give `guess` each score.
+    fn weighted_histogram(
+        &self,
+        guess: usize,
+        potential_solns: &[usize],
+        weights: &[f64],
+    ) -> Vec<f64> {
+        let mut weighted = vec![0_f64; scoring::num_scores(self.dictionary.size())];
+        self.visit_scores(guess, potential_solns, |score, soln| {
+            weighted[score] += weights[soln];
+        });
+        weighted
+    }
+
+    fn histogram(&self, guess: usize, potential_solns: &[usize]) -> Vec<u32> {
+        let mut histogram = vec![0_u32; scoring::num_scores(self.dictionary.size())];
+        self.visit_scores(guess, potential_solns, |score, _| histogram[score] += 1);
+        histogram
+    }
+
+    /// Calls `visit` with the score `guess` would receive against each of the `potential_solns`.
+    fn visit_scores(
+        &self,
+        guess: usize,
+        potential_solns: &[usize],
+        mut visit: impl FnMut(usize, usize),
+    ) {
+        match &self.matrix {
+            Some(matrix) => {
+                let scores = matrix.row(guess);
+                for &soln in potential_solns {
+                    visit(scores.get(soln) as usize, soln);
+                }
+            }
+            None => {
+                let guess = &self.dictionary.all_words[guess];
+                for &soln in potential_solns {
+                    let score = scoring::score(guess, &self.dictionary.potential_solns[soln]);
+                    visit(score as usize, soln);
+                }
+            }
+        }
+    }
+
+    fn permitted_guesses(&self, scoreboard: &Scoreboard) -> Vec<usize> {
+        let all_words = &self.dictionary.all_words;
+        (0..all_words.len())
+            .filter(|&guess| scoreboard.permits(&all_words[guess]))
             .collect()
     }
+
+    /// Groups the `potential_solns` by the score they would give `guess`.
+    pub fn partition(
+        &self,
+        guess: &Word,
+        potential_solns: &[usize],
+    ) -> BTreeMap<Score, Vec<usize>> {
+        let mut partitions: BTreeMap<Score, Vec<usize>> = BTreeMap::new();
+        let index = self.dictionary.guess_index(guess);
+
+        for &soln in potential_solns {
+            let score = match (&self.matrix, index) {
+                (Some(matrix), Some(index)) => matrix.row(index).get(soln),
+                _ => scoring::score(guess, &self.dictionary.potential_solns[soln]),
+            };
+            partitions.entry(score).or_default().push(soln);
+        }
+
+        partitions
+    }
+
+    pub fn trim_solns(
+        &self,
+        guess: &Word,
+        observed_score: Score,
+        potential_solns: &[usize],
+    ) -> Vec<usize> {
+        let index = self.dictionary.guess_index(guess);
+
+        match (&self.matrix, index) {
+            (Some(matrix), Some(index)) => {
+                let scores = matrix.row(index);
+                potential_solns
+                    .iter()
+                    .copied()
+                    .filter(|&soln| scores.get(soln) == observed_score)
+                    .collect()
+            }
+            _ => {
+                let solns = &self.dictionary.potential_solns;
+                potential_solns
+                    .iter()
+                    .copied()
+                    .filter(|&soln| scoring::score(guess, &solns[soln]) == observed_score)
+                    .collect()
+            }
+        }
+    }
 }
 
 impl<T: Algorithm> Solve for Solver<T> {
-    fn solve(&self, soln: &Word, opening_guess: Word) -> Option<Scoreboard> {
+    fn solve(&self, soln: &Word, opening_guess: Option<Word>) -> Option<Scoreboard> {
         self.run(soln, opening_guess)
     }
+
+    fn solve_interactive(&self, opening_guess: Option<Word>) -> Option<Scoreboard> {
+        let stdin = io::stdin();
+        self.run_interactive(opening_guess, &mut stdin.lock())
+    }
+
+    fn solve_adversarial(&self, opening_guess: Option<Word>) -> Option<Scoreboard> {
+        self.run_adversarial(opening_guess)
+    }
+
+    fn host_absurdle(&self) -> Option<Scoreboard> {
+        let stdin = io::stdin();
+        AbsurdleHost::new(self).run_interactive(&mut stdin.lock())
+    }
+
+    fn opening_guess(&self) -> Word {
+        Solver::opening_guess(self)
+    }
+
+    fn openers(&self, n: usize) -> Vec<GuessMetrics> {
+        let all_guesses: Vec<usize> = (0..self.dictionary.all_words.len()).collect();
+        let potential_solns: Vec<usize> = (0..self.dictionary.potential_solns.len()).collect();
+        self.top_guesses(&all_guesses, &potential_solns, n)
+    }
+
+    fn replies(&self, opening_guess: &Word) -> BTreeMap<Score, Word> {
+        Solver::replies(self, opening_guess)
+    }
+
+    fn analyse(&self, soln: &Word, guesses: &[Word]) -> Analysis {
+        Analysis::new(self, soln, guesses)
+    }
+
+    fn decision_tree(&self, opening_guess: Option<Word>) -> DecisionTree {
+        Solver::decision_tree(self, opening_guess)
+    }
+}
+
+/// Identifies everything that decides the opening guesses: the dictionary, the
+/// algorithm and the options that change which guess it picks.
+fn cache_key<T: Algorithm>(
+    dictionary: &Dictionary,
+    options: &SolverOptions,
+    weights: Option<&[f64]>,
+) -> String {
+    let mut key = format!("{:016x} {}", dictionary.fingerprint(), T::NAME);
+    if options.hard_mode {
+        key.push_str(" hard");
+    }
+    if let Some(search) = &options.search {
+        key.push_str(&format!(
+            " optimal {:?} top-{} depth-{}",
+            search.objective, search.top_k, search.max_depth
+        ));
+    }
+    if let Some(lookahead) = &options.lookahead {
+        key.push_str(&format!(" lookahead top-{}", lookahead.top_k));
+    }
+    if let Some(weights) = weights {
+        let bytes = weights.iter().flat_map(|weight| weight.to_le_bytes());
+        key.push_str(&format!(" priors {:016x}", dictionary::fnv1a(bytes)));
+    }
+
+    key
+}
+
+/// Prompts until a valid score is entered, or returns `None` at the end of the input.
+pub fn read_score(prompt: &str, size: usize, input: &mut impl BufRead) -> Option<Score> {
+    loop {
+        eprint!("{prompt}");
+
+        let mut line = String::new();
+        if input.read_line(&mut line).ok()? == 0 {
+            eprintln!();
+            return None;
+        }
+
+        match scoring::parse_score(&line, size) {
+            Some(score) => return Some(score),
+            None => eprintln!("Unable to parse '{}' as a score.", line.trim()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -116,39 +757,484 @@ mod tests {
     use crate::{guess::MinimaxAlgorithm, reporting::NullReporter};
 
     use super::*;
-    use rstest::{fixture, rstest};
+    use crate::fixtures::dictionary;
+    use float_cmp::assert_approx_eq;
+    use itertools::Itertools;
+    use rstest::rstest;
+    use std::io::Cursor;
+    use std::{env, fs};
 
     #[rstest]
     fn report_failure__with_scoreboard__prints(dictionary: Dictionary) {
         // Arrange
         let algorithm = MinimaxAlgorithm;
         let reporter = Box::new(NullReporter);
-        let sut = Solver::new(algorithm, reporter, dictionary);
-        let opening_guess: Word = "SALET".into();
-        let soln: Word = "POWER".into();
+        let sut = Solver::new(algorithm, reporter, dictionary, SolverOptions::default());
+        let opening_guess: Word = "SALET".parse().unwrap();
+        let soln: Word = "POWER".parse().unwrap();
 
         // Act
-        let actual = sut.solve(&soln, opening_guess);
+        let actual = sut.solve(&soln, Some(opening_guess));
 
         // Assert
         assert!(matches!(actual, Some(scoreboard) if scoreboard.len() == 3));
     }
 
-    #[fixture]
-    fn dictionary() -> Dictionary {
-        let all_words = vec![
-            "SALET".into(),
-            "TOWER".into(),
-            "SOARE".into(),
-            "ROWER".into(),
-            "POWER".into(),
-        ];
+    #[rstest]
+    fn run__with_priors__plays_likelier_answer_first(
+        dictionary: Dictionary,
+        #[values(true, false)] use_entropy: bool,
+    ) {
+        // Arrange
+        let path = env::temp_dir().join(format!("doddle-solver-priors-{use_entropy}.txt"));
+        fs::write(&path, "TOWER 10\nROWER 1\nPOWER 50\n").unwrap();
+        let options = SolverOptions {
+            priors: Some(Priors::load(&path).unwrap()),
+            ..Default::default()
+        };
+        fs::remove_file(&path).unwrap();
+
+        let sut: Box<dyn Solve> = if use_entropy {
+            Box::new(Solver::new(
+                EntropyAlgorithm,
+                Box::new(NullReporter),
+                dictionary,
+                options,
+            ))
+        } else {
+            Box::new(Solver::new(
+                MinimaxAlgorithm,
+                Box::new(NullReporter),
+                dictionary,
+                options,
+            ))
+        };
+
+        // Act
+        let actual = sut.solve(&"POWER".parse().unwrap(), Some("SALET".parse().unwrap()));
+
+        // Assert
+        // SALET leaves ROWER and POWER, which is far more common
+        assert_eq!(2, actual.unwrap().len());
+    }
+
+    #[rstest]
+    fn metrics__with_priors__weights_the_bits(dictionary: Dictionary) {
+        // Arrange
+        let path = env::temp_dir().join("doddle-solver-metrics-priors.txt");
+        fs::write(&path, "TOWER 10\nROWER 1\nPOWER 50\n").unwrap();
+        let options = SolverOptions {
+            priors: Some(Priors::load(&path).unwrap()),
+            ..Default::default()
+        };
+        fs::remove_file(&path).unwrap();
+        let sut = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            options,
+        );
+
+        // Act
+        let actual = sut.metrics(0, &[0, 1, 2]);
+
+        // Assert
+        // SALET only sets TOWER apart, which is far less likely than ROWER or POWER
+        let expected: f64 = [10.0_f64 / 61.0, 51.0 / 61.0]
+            .iter()
+            .map(|p| -p * p.log2())
+            .sum();
+        assert_approx_eq!(f64, expected, actual.entropy, epsilon = 1e-9);
+        assert_approx_eq!(
+            f64,
+            sut.entropy(0, &[0, 1, 2]),
+            actual.entropy,
+            epsilon = 1e-9
+        );
+        assert_eq!(2, actual.largest_bucket);
+    }
+
+    #[rstest]
+    fn opening_guess__with_cache__is_searched_for_once(dictionary: Dictionary) {
+        // Arrange
+        let path = env::temp_dir().join("doddle-solver-cache.json");
+        let options = SolverOptions {
+            cache: Some(path.clone()),
+            ..Default::default()
+        };
+        let sut = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            dictionary.clone(),
+            options.clone(),
+        );
+
+        // Act
+        let opening_guess = sut.opening_guess();
+        let replies = sut.replies(&opening_guess);
+        let mut cached = OpeningCache::load(&path);
+        let saved = cached.get(&sut.cache_key).cloned();
 
-        let potential_solns = vec!["TOWER".into(), "ROWER".into(), "POWER".into()];
+        // Poison the cache, so that a later solver can only return these by looking them up
+        let poisoned: Word = if opening_guess.value() == "SOARE" {
+            "SALET".parse().unwrap()
+        } else {
+            "SOARE".parse().unwrap()
+        };
+        let poisoned_replies = BTreeMap::from([(0, opening_guess.clone())]);
+        let openings = cached.entry(&sut.cache_key);
+        openings.opening = Some(poisoned.clone());
+        openings
+            .replies
+            .insert(poisoned.clone(), poisoned_replies.clone());
+        cached.save().unwrap();
+
+        let later = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            options,
+        );
+        let later_opening_guess = later.opening_guess();
+        let later_replies = later.replies(&poisoned);
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        let saved = saved.unwrap();
+        assert_eq!(Some(&opening_guess), saved.opening.as_ref());
+        assert_eq!(Some(&replies), saved.replies.get(&opening_guess));
+        assert_eq!(poisoned, later_opening_guess);
+        assert_eq!(poisoned_replies, later_replies);
+    }
+
+    #[rstest]
+    fn run__with_cache__matches_uncached(
+        dictionary: Dictionary,
+        #[values("TOWER", "ROWER", "POWER")] soln: &str,
+    ) {
+        // Arrange
+        let soln: Word = soln.parse().unwrap();
+        let path = env::temp_dir().join(format!("doddle-solver-cache-{soln}.json"));
+        let options = SolverOptions {
+            cache: Some(path.clone()),
+            ..Default::default()
+        };
+
+        let direct = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary.clone(),
+            SolverOptions::default(),
+        );
+        let sut = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            options,
+        );
+
+        // Act
+        let expected = direct.run(&soln, Some("SALET".parse().unwrap())).unwrap();
+        let actual = sut.run(&soln, Some("SALET".parse().unwrap())).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Assert
+        let mut rows = expected.rows.iter().zip(&actual.rows);
+        assert_eq!(expected.len(), actual.len());
+        assert!(rows.all(|(e, a)| e.guess == a.guess && e.score == a.score));
+    }
+
+    #[rstest]
+    fn cache_key__for_different_options__differs(dictionary: Dictionary) {
+        // Arrange
+        let hard_mode = SolverOptions {
+            hard_mode: true,
+            ..Default::default()
+        };
+
+        // Act
+        let entropy = cache_key::<EntropyAlgorithm>(&dictionary, &SolverOptions::default(), None);
+        let minimax = cache_key::<MinimaxAlgorithm>(&dictionary, &SolverOptions::default(), None);
+        let hard = cache_key::<EntropyAlgorithm>(&dictionary, &hard_mode, None);
+        let weighted =
+            cache_key::<EntropyAlgorithm>(&dictionary, &SolverOptions::default(), Some(&[1.0]));
+
+        // Assert
+        assert!(entropy.ends_with(" entropy"));
+        assert_eq!(
+            4,
+            [&entropy, &minimax, &hard, &weighted]
+                .iter()
+                .unique()
+                .count()
+        );
+    }
+
+    #[rstest]
+    fn run_interactive__with_scores__solves(dictionary: Dictionary) {
+        // Arrange
+        let sut = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            SolverOptions::default(),
+        );
+        let opening_guess: Word = "TOWER".parse().unwrap();
+        let mut input = Cursor::new("02222\nnonsense\nBGGGG\n22222\n");
+
+        // Act
+        let actual = sut.run_interactive(Some(opening_guess), &mut input);
+
+        // Assert
+        let scoreboard = actual.unwrap();
+        let guesses: Vec<String> = scoreboard.rows.iter().map(|r| r.guess.value()).collect();
+        assert_eq!(vec!["TOWER", "ROWER", "POWER"], guesses);
+        assert!(scoreboard.rows.iter().all(|r| r.soln.is_none()));
+    }
+
+    #[rstest]
+    fn run_interactive__with_inconsistent_scores__returns_none(dictionary: Dictionary) {
+        // Arrange
+        let sut = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            SolverOptions::default(),
+        );
+        let opening_guess: Word = "SALET".parse().unwrap();
+        let mut input = Cursor::new("00000\n");
+
+        // Act
+        let actual = sut.run_interactive(Some(opening_guess), &mut input);
 
-        Dictionary {
-            all_words,
-            potential_solns,
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[rstest]
+    fn run_interactive__at_end_of_input__returns_none(dictionary: Dictionary) {
+        // Arrange
+        let sut = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            SolverOptions::default(),
+        );
+        let opening_guess: Word = "SALET".parse().unwrap();
+        let mut input = Cursor::new("00020\n");
+
+        // Act
+        let actual = sut.run_interactive(Some(opening_guess), &mut input);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[rstest]
+    fn best_guess__on_many_threads__matches_single_thread(dictionary: Dictionary) {
+        // Arrange
+        let all_words: Vec<usize> = (0..dictionary.all_words.len()).collect();
+        let potential_solns: Vec<usize> = (0..dictionary.potential_solns.len()).collect();
+
+        let single = SolverOptions {
+            num_threads: 1,
+            ..Default::default()
+        };
+        let many = SolverOptions {
+            num_threads: 4,
+            ..Default::default()
+        };
+
+        let sut_single = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            dictionary.clone(),
+            single,
+        );
+        let sut_many = Solver::new(EntropyAlgorithm, Box::new(NullReporter), dictionary, many);
+
+        // Act
+        let expected: Word = sut_single.best_guess(&all_words, &potential_solns).into();
+        let actual: Word = sut_many.best_guess(&all_words, &potential_solns).into();
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    fn run__with_score_matrix__matches_direct_scoring(
+        dictionary: Dictionary,
+        #[values("TOWER", "ROWER", "POWER")] soln: &str,
+    ) {
+        // Arrange
+        let soln: Word = soln.parse().unwrap();
+        let options = SolverOptions {
+            matrix: MatrixSource::Memory,
+            ..Default::default()
+        };
+
+        let direct = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            dictionary.clone(),
+            SolverOptions::default(),
+        );
+        let sut = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            options,
+        );
+
+        // Act
+        let expected = direct.run(&soln, Some("SALET".parse().unwrap())).unwrap();
+        let actual = sut.run(&soln, Some("SALET".parse().unwrap())).unwrap();
+
+        // Assert
+        let mut rows = expected.rows.iter().zip(&actual.rows);
+        assert_eq!(expected.len(), actual.len());
+        assert!(rows.all(|(e, a)| e.guess == a.guess && e.score == a.score));
+    }
+
+    #[rstest]
+    fn top_guesses__for_fixture__ranks_with_metrics(dictionary: Dictionary) {
+        // Arrange
+        let sut = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            SolverOptions::default(),
+        );
+        let guesses: Vec<usize> = (0..5).collect();
+
+        // Act
+        let actual = sut.top_guesses(&guesses, &[0, 1, 2], 2);
+
+        // Assert
+        // Every guess leaves two answers at worst, but TOWER might also win outright
+        let words: Vec<String> = actual.iter().map(|m| m.word.value()).collect();
+        assert_eq!(vec!["TOWER", "ROWER"], words);
+        assert!(actual
+            .iter()
+            .all(|m| m.largest_bucket == 2 && m.num_buckets == 2));
+        assert!(actual.iter().all(|m| m.is_potential_soln));
+    }
+
+    #[rstest]
+    fn run_adversarial__against_host__solves(
+        dictionary: Dictionary,
+        #[values(true, false)] use_entropy: bool,
+    ) {
+        // Arrange
+        let sut: Box<dyn Solve> = if use_entropy {
+            Box::new(Solver::new(
+                EntropyAlgorithm,
+                Box::new(NullReporter),
+                dictionary,
+                SolverOptions::default(),
+            ))
+        } else {
+            Box::new(Solver::new(
+                MinimaxAlgorithm,
+                Box::new(NullReporter),
+                dictionary,
+                SolverOptions::default(),
+            ))
+        };
+
+        // Act
+        let actual = sut.solve_adversarial(Some("SALET".parse().unwrap()));
+
+        // Assert
+        // SALET leaves ROWER and POWER, so the host makes the first guess of those wrong
+        let scoreboard = actual.unwrap();
+        assert_eq!(3, scoreboard.len());
+        assert!(scoreboard.is_solved());
+    }
+
+    #[rstest]
+    #[case("SALET", vec![2, 1, 1, 0])]
+    #[case("ROWER", vec![2, 1, 1])]
+    #[case("TOWER", vec![2, 1, 1])]
+    fn combined_histogram__for_two_boards__joins_buckets(
+        dictionary: Dictionary,
+        #[case] guess: &str,
+        #[case] expected: Vec<u32>,
+    ) {
+        // Arrange
+        let sut = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            SolverOptions::default(),
+        );
+        let guess = sut.dictionary.guess_index(&guess.parse().unwrap()).unwrap();
+        let boards: [&[usize]; 2] = [&[0, 1, 2], &[0]];
+
+        // Act
+        let actual = sut.combined_histogram(guess, &boards);
+
+        // Assert
+        // A guess that solves both boards keeps a separate win bucket for each
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    fn decision_tree__for_each_soln__matches_solve(
+        dictionary: Dictionary,
+        #[values(false, true)] hard_mode: bool,
+    ) {
+        // Arrange
+        let solns = dictionary.potential_solns.clone();
+        let options = SolverOptions {
+            hard_mode,
+            ..Default::default()
+        };
+        let sut = Solver::new(
+            MinimaxAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            options,
+        );
+        let opening_guess: Word = "SALET".parse().unwrap();
+
+        // Act
+        let actual = sut.decision_tree(Some(opening_guess.clone()));
+
+        // Assert
+        let depths = actual.depths();
+        assert_eq!(solns.len(), depths.len());
+        for (soln, depth) in depths {
+            let scoreboard = sut.solve(&soln, Some(opening_guess.clone())).unwrap();
+            assert_eq!(scoreboard.len(), depth, "{soln}");
         }
     }
+
+    #[rstest]
+    #[case(&["FISH", "DISH", "WISH", "FIST", "MIST", "DUSK"], "MIST")]
+    #[case(&["CALIPER", "SCALPEL", "REPLICA", "PLACERS", "CARPELS"], "CARPELS")]
+    fn run__for_other_lengths__solves(#[case] words: &[&str], #[case] soln: &str) {
+        // Arrange
+        let words: Vec<Word> = words.iter().map(|w| w.parse().unwrap()).collect();
+        let dictionary = Dictionary {
+            all_words: words.clone(),
+            potential_solns: words,
+        };
+
+        let options = SolverOptions::default();
+        let sut = Solver::new(
+            EntropyAlgorithm,
+            Box::new(NullReporter),
+            dictionary,
+            options,
+        );
+        let soln: Word = soln.parse().unwrap();
+
+        // Act
+        let actual = sut.run(&soln, None);
+
+        // Assert
+        let scoreboard = actual.unwrap();
+        assert_eq!(soln, scoreboard.rows.last().unwrap().guess);
+    }
 }
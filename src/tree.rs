@@ -0,0 +1,269 @@
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result, Write};
+
+use crate::scoring::{score_to_str, Score};
+use crate::word::Word;
+
+/// A complete solving strategy: the guess to play, and the subtree to follow
+/// for every score that guess could receive other than a win.
+pub struct DecisionTree {
+    pub guess: Word,
+    pub num_solns: usize,
+    pub is_potential_soln: bool,
+    pub branches: Vec<(Score, DecisionTree)>,
+    /// Answers the solver gave up on after this guess, having run out of iterations.
+    pub unsolved: usize,
+}
+
+impl DecisionTree {
+    /// The number of guesses needed to solve each answer covered by the tree.
+    pub fn depths(&self) -> Vec<(Word, usize)> {
+        let mut depths = Vec::new();
+        self.collect_depths(1, &mut depths);
+        depths
+    }
+
+    fn collect_depths(&self, depth: usize, depths: &mut Vec<(Word, usize)>) {
+        if self.is_potential_soln {
+            depths.push((self.guess.clone(), depth));
+        }
+
+        for (_, branch) in &self.branches {
+            branch.collect_depths(depth + 1, depths);
+        }
+    }
+
+    /// The number of answers the tree gives up on, which the depth statistics exclude.
+    pub fn failures(&self) -> usize {
+        let nested: usize = self
+            .branches
+            .iter()
+            .map(|(_, branch)| branch.failures())
+            .sum();
+        self.unsolved + nested
+    }
+
+    pub fn distribution(&self) -> BTreeMap<usize, usize> {
+        let mut distribution = BTreeMap::new();
+        for (_, depth) in self.depths() {
+            *distribution.entry(depth).or_insert(0) += 1;
+        }
+
+        distribution
+    }
+
+    /// The sum of guesses needed over every answer.
+    pub fn total_guesses(&self) -> usize {
+        self.depths().iter().map(|(_, depth)| depth).sum()
+    }
+
+    pub fn mean(&self) -> f64 {
+        let depths = self.depths();
+        if depths.is_empty() {
+            return 0.0;
+        }
+
+        self.total_guesses() as f64 / depths.len() as f64
+    }
+
+    pub fn max(&self) -> usize {
+        self.depths()
+            .iter()
+            .map(|(_, depth)| *depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The tree as JSON, with branches keyed by their ternary score.
+    pub fn to_json(&self) -> Value {
+        let distribution: Map<String, Value> = self
+            .distribution()
+            .into_iter()
+            .map(|(depth, count)| (depth.to_string(), json!(count)))
+            .collect();
+
+        json!({
+            "opening": self.guess.value(),
+            "stats": {
+                "answers": self.depths().len(),
+                "failures": self.failures(),
+                "total_guesses": self.total_guesses(),
+                "mean": self.mean(),
+                "max": self.max(),
+                "distribution": distribution,
+            },
+            "tree": self.node_json(),
+        })
+    }
+
+    fn node_json(&self) -> Value {
+        let branches: Map<String, Value> = self
+            .branches
+            .iter()
+            .map(|(score, branch)| (score_to_str(*score, self.guess.len()), branch.node_json()))
+            .collect();
+
+        json!({
+            "guess": self.guess.value(),
+            "candidates": self.num_solns,
+            "branches": branches,
+        })
+    }
+
+    /// The tree as a Graphviz digraph. Guesses that may be the answer are circled twice.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph strategy {\n    node [shape=box];\n");
+        let mut next_id = 0;
+        self.write_dot(&mut dot, &mut next_id);
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn write_dot(&self, dot: &mut String, next_id: &mut usize) -> usize {
+        let id = *next_id;
+        *next_id += 1;
+
+        let peripheries = if self.is_potential_soln { 2 } else { 1 };
+        writeln!(
+            dot,
+            "    n{id} [label=\"{}\\n({})\", peripheries={peripheries}];",
+            self.guess, self.num_solns
+        )
+        .unwrap();
+
+        for (score, branch) in &self.branches {
+            let child = branch.write_dot(dot, next_id);
+            let label = score_to_str(*score, self.guess.len());
+            writeln!(dot, "    n{id} -> n{child} [label=\"{label}\"];").unwrap();
+        }
+
+        id
+    }
+}
+
+impl Display for DecisionTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(
+            f,
+            "Opening with {} solves {} answers",
+            self.guess,
+            self.depths().len()
+        )?;
+        if self.failures() > 0 {
+            writeln!(f, "Failed to solve {} answers", self.failures())?;
+        }
+        writeln!(f)?;
+        writeln!(f, "| Guesses | Games |")?;
+        writeln!(f, "|---------|-------|")?;
+        for (num_guesses, count) in self.distribution() {
+            writeln!(f, "| {num_guesses: >7} | {count: >5} |")?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Total guesses: {}", self.total_guesses())?;
+        writeln!(f, "Mean: {:.4}", self.mean())?;
+        write!(f, "Max: {}", self.max())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+
+    use super::*;
+    use crate::scoring::str_to_score;
+    use float_cmp::assert_approx_eq;
+    use rstest::{fixture, rstest};
+
+    #[rstest]
+    fn statistics__for_tree__summarise(tree: DecisionTree) {
+        // Act
+        let distribution = tree.distribution();
+
+        // Assert
+        assert_eq!(3, tree.depths().len());
+        assert_eq!(Some(&1), distribution.get(&2));
+        assert_eq!(Some(&1), distribution.get(&4));
+        assert_eq!(9, tree.total_guesses());
+        assert_approx_eq!(f64, 3.0, tree.mean(), epsilon = 1e-9);
+        assert_eq!(4, tree.max());
+    }
+
+    #[rstest]
+    fn to_json__for_tree__nests_branches_by_score(tree: DecisionTree) {
+        // Act
+        let actual = tree.to_json();
+
+        // Assert
+        assert_eq!("SALET", actual["opening"]);
+        assert_eq!(9, actual["stats"]["total_guesses"]);
+        assert_eq!(1, actual["stats"]["distribution"]["3"]);
+        let tower = &actual["tree"]["branches"]["00012"];
+        assert_eq!("TOWER", tower["guess"]);
+        assert_eq!("POWER", tower["branches"]["02222"]["guess"]);
+    }
+
+    #[rstest]
+    fn failures__for_abandoned_branches__are_reported(mut tree: DecisionTree) {
+        // Arrange
+        tree.unsolved = 2;
+        tree.branches[0].1.unsolved = 1;
+
+        // Act
+        let actual = tree.to_json();
+
+        // Assert
+        assert_eq!(3, tree.failures());
+        assert_eq!(3, actual["stats"]["failures"]);
+        assert!(tree.to_string().contains("Failed to solve 3 answers"));
+    }
+
+    #[rstest]
+    fn to_dot__for_tree__lists_nodes_and_edges(tree: DecisionTree) {
+        // Act
+        let actual = tree.to_dot();
+
+        // Assert
+        assert!(actual.starts_with("digraph strategy {"));
+        assert!(actual.contains("n0 [label=\"SALET\\n(3)\", peripheries=1];"));
+        assert!(actual.contains("n1 [label=\"TOWER\\n(3)\", peripheries=2];"));
+        assert!(actual.contains("n0 -> n1 [label=\"00012\"];"));
+        assert_eq!(3, actual.matches("->").count());
+    }
+
+    #[fixture]
+    fn tree() -> DecisionTree {
+        let leaf = |guess: &str| DecisionTree {
+            guess: guess.parse().unwrap(),
+            num_solns: 1,
+            is_potential_soln: true,
+            branches: Vec::new(),
+            unsolved: 0,
+        };
+
+        let power = DecisionTree {
+            guess: "POWER".parse().unwrap(),
+            num_solns: 2,
+            is_potential_soln: true,
+            branches: vec![(str_to_score("02222"), leaf("ROWER"))],
+            unsolved: 0,
+        };
+
+        let tower = DecisionTree {
+            guess: "TOWER".parse().unwrap(),
+            num_solns: 3,
+            is_potential_soln: true,
+            branches: vec![(str_to_score("02222"), power)],
+            unsolved: 0,
+        };
+
+        DecisionTree {
+            guess: "SALET".parse().unwrap(),
+            num_solns: 3,
+            is_potential_soln: false,
+            branches: vec![(str_to_score("00012"), tower)],
+            unsolved: 0,
+        }
+    }
+}
@@ -1,42 +1,127 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result};
+use std::str::FromStr;
 
-pub const SIZE: usize = 5;
+/// The number of letters in a standard game of Wordle.
+pub const DEFAULT_SIZE: usize = 5;
 
-#[derive(Clone, PartialEq, Eq)]
+/// The longest word supported. Scores for ten letters still fit in a `u16`.
+pub const MAX_SIZE: usize = 10;
+
+/// Written to JSON as its letters, such as `"SALET"`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
 pub struct Word {
-    pub vector: [u8; 5],
+    vector: [u8; MAX_SIZE],
+    size: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WordError {
+    /// The word is empty or has more than `MAX_SIZE` letters.
+    InvalidLength(String),
+
+    /// The word does not have as many letters as the game being played.
+    WrongLength { word: String, expected: usize },
+
+    /// The word contains something other than the letters A to Z.
+    InvalidCharacter { word: String, character: char },
+
+    /// The word is not in the dictionary.
+    NotInDictionary(String),
+}
+
+impl Display for WordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            WordError::InvalidLength(word) => {
+                write!(f, "'{word}' must have between 1 and {MAX_SIZE} letters")
+            }
+            WordError::WrongLength { word, expected } => {
+                write!(f, "'{word}' does not have {expected} letters")
+            }
+            WordError::InvalidCharacter { word, character } => {
+                write!(
+                    f,
+                    "'{word}' contains '{character}' which is not a letter from A to Z"
+                )
+            }
+            WordError::NotInDictionary(word) => write!(f, "'{word}' is not in the dictionary"),
+        }
+    }
 }
 
+impl Error for WordError {}
+
 impl Word {
-    pub fn new(value: &str) -> Word {
-        assert_eq!(SIZE, value.len());
-        let upper = value.to_uppercase();
-        let mut vector: [u8; SIZE] = [0; SIZE];
+    /// Builds a word from its letters as ordinals, where `A` is zero.
+    pub const fn from_letters(vector: [u8; MAX_SIZE], size: usize) -> Word {
+        Word { vector, size }
+    }
 
-        for (i, char) in upper.char_indices() {
-            let char_ordinal = char as u8;
+    /// The letters of the word as ordinals, where `A` is zero.
+    pub fn letters(&self) -> &[u8] {
+        &self.vector[..self.size]
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn value(&self) -> String {
+        let values = self.letters().iter().map(|e| e + b'A').collect();
+        String::from_utf8(values).unwrap()
+    }
+}
+
+impl FromStr for Word {
+    type Err = WordError;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        let size = value.chars().count();
+        if size == 0 || size > MAX_SIZE {
+            return Err(WordError::InvalidLength(value.to_string()));
+        }
+
+        let mut vector: [u8; MAX_SIZE] = [0; MAX_SIZE];
+        for (i, char) in value.chars().enumerate() {
+            if !char.is_ascii_alphabetic() {
+                let word = value.to_string();
+                return Err(WordError::InvalidCharacter {
+                    word,
+                    character: char,
+                });
+            }
+
+            let char_ordinal = char.to_ascii_uppercase() as u8;
             let ordinal = char_ordinal - b'A';
             vector[i] = ordinal;
         }
 
-        Word { vector }
+        Ok(Word { vector, size })
     }
+}
 
-    pub fn value(&self) -> String {
-        let values = self.vector.into_iter().map(|e| e + b'A').collect();
-        String::from_utf8(values).unwrap()
+impl TryFrom<&str> for Word {
+    type Error = WordError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        value.parse()
     }
 }
 
-impl From<String> for Word {
-    fn from(value: String) -> Self {
-        Self::new(&value)
+impl TryFrom<String> for Word {
+    type Error = WordError;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        value.parse()
     }
 }
 
-impl From<&str> for Word {
-    fn from(value: &str) -> Self {
-        Self::new(value)
+impl From<Word> for String {
+    fn from(word: Word) -> Self {
+        word.value()
     }
 }
 
@@ -59,11 +144,65 @@ impl Debug for Word {
 mod tests {
 
     use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn serialize__then_deserialize__roundtrips() {
+        // Arrange
+        let word: Word = "SALET".parse().unwrap();
+
+        // Act
+        let json = serde_json::to_string(&word).unwrap();
+        let actual: Word = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!("\"SALET\"", json);
+        assert_eq!(word, actual);
+        assert!(serde_json::from_str::<Word>("\"S4LET\"").is_err());
+    }
+
+    #[test]
+    fn parse__for_lowercase__uppercases() {
+        // Act
+        let actual: Word = "space".parse().unwrap();
+
+        // Assert
+        assert_eq!("SPACE", actual.value());
+    }
+
+    #[rstest]
+    #[case("", WordError::InvalidLength("".into()))]
+    #[case("ABCDEFGHIJK", WordError::InvalidLength("ABCDEFGHIJK".into()))]
+    #[case("SP4CE", WordError::InvalidCharacter { word: "SP4CE".into(), character: '4' })]
+    #[case("SPÄCE", WordError::InvalidCharacter { word: "SPÄCE".into(), character: 'Ä' })]
+    #[case("SPA E", WordError::InvalidCharacter { word: "SPA E".into(), character: ' ' })]
+    fn try_from__for_bad_input__errors(#[case] value: &str, #[case] expected: WordError) {
+        // Act
+        let actual = Word::try_from(value);
+
+        // Assert
+        assert_eq!(Err(expected), actual);
+    }
+
+    #[test]
+    fn display__for_error__describes_problem() {
+        // Arrange
+        let error = WordError::WrongLength {
+            word: "SPACES".into(),
+            expected: 5,
+        };
+
+        // Act
+        let actual = error.to_string();
+
+        // Assert
+        assert_eq!("'SPACES' does not have 5 letters", actual);
+    }
 
     #[test]
     fn display__via_format__displays() {
         // Arrange
-        let word = Word::new("space");
+        let word: Word = "space".parse().unwrap();
         let expected = "Word is: SPACE".to_string();
 
         // Act
@@ -73,10 +212,35 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn new__for_other_lengths__keeps_every_letter() {
+        // Arrange
+        let values = ["FISH", "SPACES", "SPACIER", "ABCDEFGHIJ"];
+
+        for value in values {
+            // Act
+            let word: Word = value.parse().unwrap();
+
+            // Assert
+            assert_eq!(value.len(), word.len());
+            assert_eq!(value, word.value());
+        }
+    }
+
+    #[test]
+    fn eq__for_prefix__is_not_equal() {
+        // Arrange
+        let shorter: Word = "BAA".parse().unwrap();
+        let longer: Word = "BAAA".parse().unwrap();
+
+        // Assert
+        assert_ne!(shorter, longer);
+    }
+
     #[test]
     fn debug__via_format__debugs() {
         // Arrange
-        let word = Word::new("space");
+        let word: Word = "space".parse().unwrap();
         let expected = "Word { vector: \"SPACE\" }".to_string();
 
         // Act